@@ -1,4 +1,8 @@
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(nightly)");
+    println!("cargo:rustc-check-cfg=cfg(const_fn_transmute)");
+    println!("cargo:rustc-check-cfg=cfg(try_reserve)");
+
     if let Some(true) = version_check::is_feature_flaggable() {
         println!("cargo:rustc-cfg=nightly");
     }
@@ -6,4 +10,8 @@ fn main() {
     if let Some(true) = version_check::is_min_version("1.56.0") {
         println!("cargo:rustc-cfg=const_fn_transmute");
     }
+
+    if let Some(true) = version_check::is_min_version("1.57.0") {
+        println!("cargo:rustc-cfg=try_reserve");
+    }
 }
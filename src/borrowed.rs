@@ -1,9 +1,10 @@
 use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
 use core::fmt;
+use core::convert::TryFrom;
 
 #[cfg(feature = "alloc")]
-use alloc::{string::String, sync::Arc};
+use alloc::{borrow::Cow, string::String, sync::Arc};
 
 /// A cost-free reference to an uncased (case-insensitive, case-preserving)
 /// ASCII string.
@@ -15,7 +16,6 @@ use alloc::{string::String, sync::Arc};
 ///
 /// let ascii_ref: &UncasedStr = "Hello, world!".into();
 /// ```
-#[derive(Debug)]
 #[repr(transparent)]
 pub struct UncasedStr(str);
 
@@ -80,6 +80,67 @@ impl UncasedStr {
         &self.0
     }
 
+    /// Cost-free conversion from an `&mut str` reference to an `&mut
+    /// UncasedStr`.
+    #[inline(always)]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new_mut(string: &mut str) -> &mut UncasedStr {
+        // This is a `newtype`-like transformation. `repr(transparent)` ensures
+        // that this is safe and correct.
+        unsafe { &mut *(string as *mut str as *mut UncasedStr) }
+    }
+
+    /// Converts `self` to its ASCII lowercase equivalent in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::borrow::BorrowMut;
+    /// use uncased::{Uncased, UncasedStr};
+    ///
+    /// let mut uncased = Uncased::new("Content-Type");
+    /// let uncased_str: &mut UncasedStr = uncased.borrow_mut();
+    /// uncased_str.make_ascii_lowercase();
+    /// assert_eq!(uncased.as_str(), "content-type");
+    /// ```
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.0.make_ascii_lowercase()
+    }
+
+    /// Converts `self` to its ASCII uppercase equivalent in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::borrow::BorrowMut;
+    /// use uncased::{Uncased, UncasedStr};
+    ///
+    /// let mut uncased = Uncased::new("Content-Type");
+    /// let uncased_str: &mut UncasedStr = uncased.borrow_mut();
+    /// uncased_str.make_ascii_uppercase();
+    /// assert_eq!(uncased.as_str(), "CONTENT-TYPE");
+    /// ```
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.0.make_ascii_uppercase()
+    }
+
+    /// Returns `self` as an `&UncasedBytes`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::{UncasedStr, UncasedBytes};
+    ///
+    /// let uncased_str = UncasedStr::new("Hello!");
+    /// assert_eq!(uncased_str.as_uncased_bytes(), UncasedBytes::new(b"HELLO!"));
+    /// ```
+    #[inline(always)]
+    pub fn as_uncased_bytes(&self) -> &crate::UncasedBytes {
+        crate::UncasedBytes::new(self.as_str().as_bytes())
+    }
+
     /// Returns the length, in bytes, of `self`.
     ///
     /// # Example
@@ -87,59 +148,962 @@ impl UncasedStr {
     /// ```rust
     /// use uncased::UncasedStr;
     ///
-    /// let uncased_str = UncasedStr::new("Hello!");
-    /// assert_eq!(uncased_str.len(), 6);
+    /// let uncased_str = UncasedStr::new("Hello!");
+    /// assert_eq!(uncased_str.len(), 6);
+    /// ```
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns a raw pointer to the first byte of `self`. Together with
+    /// [`UncasedStr::len()`], this describes the case-preserved UTF-8 data
+    /// underlying `self`, for example, to pass across an FFI boundary.
+    ///
+    /// The pointer is only valid for as long as `self` is, and the bytes it
+    /// points to must never be written to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new("Hello!");
+    /// let (ptr, len) = (uncased_str.as_ptr(), uncased_str.len());
+    /// let bytes = unsafe { std::slice::from_raw_parts(ptr, len) };
+    /// assert_eq!(bytes, b"Hello!");
+    /// ```
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.as_str().as_ptr()
+    }
+
+    /// Returns the number of `char`s in `self` before byte index `byte_idx`,
+    /// or `None` if `byte_idx` is out of bounds or does not lie on a `char`
+    /// boundary. An index equal to `self.len()` is in bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new("Bèe!");
+    /// assert_eq!(uncased_str.byte_to_char_index(0), Some(0));
+    /// assert_eq!(uncased_str.byte_to_char_index(1), Some(1));
+    /// assert_eq!(uncased_str.byte_to_char_index(2), None);
+    /// assert_eq!(uncased_str.byte_to_char_index(3), Some(2));
+    /// assert_eq!(uncased_str.byte_to_char_index(5), Some(4));
+    /// assert_eq!(uncased_str.byte_to_char_index(6), None);
+    /// ```
+    #[inline]
+    pub fn byte_to_char_index(&self, byte_idx: usize) -> Option<usize> {
+        self.as_str().get(..byte_idx).map(|prefix| prefix.chars().count())
+    }
+
+    /// Returns `true` if `self` has a length of zero bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uncased::UncasedStr;
+    ///
+    /// let s = UncasedStr::new("");
+    /// assert!(s.is_empty());
+    ///
+    /// let s = UncasedStr::new("not empty");
+    /// assert!(!s.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+
+    /// Returns `self` with leading and trailing ASCII whitespace removed.
+    /// Unlike [`str::trim()`], non-ASCII whitespace is preserved.
+    ///
+    /// This is a `const fn` on Rust 1.56+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new("\t Hello, World! \n");
+    /// assert_eq!(uncased_str.trim_ascii().as_str(), "Hello, World!");
+    ///
+    /// let uncased_str = UncasedStr::new("\u{3000}Hi ");
+    /// assert_eq!(uncased_str.trim_ascii().as_str(), "\u{3000}Hi");
+    /// ```
+    #[inline]
+    #[cfg(not(const_fn_transmute))]
+    pub fn trim_ascii(&self) -> &UncasedStr {
+        self.trim_ascii_start().trim_ascii_end()
+    }
+
+    /// Returns `self` with leading and trailing ASCII whitespace removed.
+    /// Unlike [`str::trim()`], non-ASCII whitespace is preserved.
+    ///
+    /// This is a `const fn` on Rust 1.56+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new("\t Hello, World! \n");
+    /// assert_eq!(uncased_str.trim_ascii().as_str(), "Hello, World!");
+    ///
+    /// let uncased_str = UncasedStr::new("\u{3000}Hi ");
+    /// assert_eq!(uncased_str.trim_ascii().as_str(), "\u{3000}Hi");
+    /// ```
+    #[inline]
+    #[cfg(const_fn_transmute)]
+    pub const fn trim_ascii(&self) -> &UncasedStr {
+        self.trim_ascii_start().trim_ascii_end()
+    }
+
+    /// Returns `self` with leading ASCII whitespace removed.
+    ///
+    /// This is a `const fn` on Rust 1.56+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new(" \t Hello ");
+    /// assert_eq!(uncased_str.trim_ascii_start().as_str(), "Hello ");
+    /// ```
+    #[inline]
+    #[cfg(not(const_fn_transmute))]
+    pub fn trim_ascii_start(&self) -> &UncasedStr {
+        UncasedStr::new(self.as_str().trim_start_matches(|c: char| c.is_ascii_whitespace()))
+    }
+
+    /// Returns `self` with leading ASCII whitespace removed.
+    ///
+    /// This is a `const fn` on Rust 1.56+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new(" \t Hello ");
+    /// assert_eq!(uncased_str.trim_ascii_start().as_str(), "Hello ");
+    /// ```
+    #[inline]
+    #[cfg(const_fn_transmute)]
+    pub const fn trim_ascii_start(&self) -> &UncasedStr {
+        let mut bytes = self.0.as_bytes();
+        while let [first, rest @ ..] = bytes {
+            if !first.is_ascii_whitespace() {
+                break;
+            }
+
+            bytes = rest;
+        }
+
+        // SAFETY: Only ASCII bytes were removed from the start of a valid
+        // UTF-8 string, so `bytes` is still valid UTF-8.
+        UncasedStr::new(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Returns `self` with trailing ASCII whitespace removed.
+    ///
+    /// This is a `const fn` on Rust 1.56+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new(" Hello \t\r\n");
+    /// assert_eq!(uncased_str.trim_ascii_end().as_str(), " Hello");
+    /// ```
+    #[inline]
+    #[cfg(not(const_fn_transmute))]
+    pub fn trim_ascii_end(&self) -> &UncasedStr {
+        UncasedStr::new(self.as_str().trim_end_matches(|c: char| c.is_ascii_whitespace()))
+    }
+
+    /// Returns `self` with trailing ASCII whitespace removed.
+    ///
+    /// This is a `const fn` on Rust 1.56+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new(" Hello \t\r\n");
+    /// assert_eq!(uncased_str.trim_ascii_end().as_str(), " Hello");
+    /// ```
+    #[inline]
+    #[cfg(const_fn_transmute)]
+    pub const fn trim_ascii_end(&self) -> &UncasedStr {
+        let mut bytes = self.0.as_bytes();
+        while let [rest @ .., last] = bytes {
+            if !last.is_ascii_whitespace() {
+                break;
+            }
+
+            bytes = rest;
+        }
+
+        // SAFETY: Only ASCII bytes were removed from the end of a valid
+        // UTF-8 string, so `bytes` is still valid UTF-8.
+        UncasedStr::new(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Returns `true` if `self` starts with any casing of the string `string`;
+    /// otherwise, returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let uncased_str = UncasedStr::new("MoOO");
+    /// assert!(uncased_str.starts_with("moo"));
+    /// assert!(uncased_str.starts_with("MOO"));
+    /// assert!(uncased_str.starts_with("MOOO"));
+    /// assert!(!uncased_str.starts_with("boo"));
+    ///
+    /// let uncased_str = UncasedStr::new("Bèe");
+    /// assert!(!uncased_str.starts_with("Be"));
+    /// assert!(uncased_str.starts_with("Bè"));
+    /// assert!(uncased_str.starts_with("Bè"));
+    /// assert!(uncased_str.starts_with("bèe"));
+    /// assert!(uncased_str.starts_with("BèE"));
+    /// ```
+    #[inline(always)]
+    pub fn starts_with(&self, string: &str) -> bool {
+        self.as_str()
+            .get(..string.len())
+            .map(|s| Self::new(s) == string)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if `self` starts with any of `prefixes` without
+    /// considering case. Returns `false` if `prefixes` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let content_type = UncasedStr::new("Image/PNG");
+    /// assert!(content_type.starts_with_any(["text/", "image/"]));
+    /// assert!(!content_type.starts_with_any(["text/", "audio/"]));
+    /// assert!(!content_type.starts_with_any::<_, &str>([]));
+    /// ```
+    #[inline]
+    pub fn starts_with_any<I, S>(&self, prefixes: I) -> bool
+        where I: IntoIterator<Item = S>, S: AsRef<str>
+    {
+        prefixes.into_iter().any(|prefix| self.starts_with(prefix.as_ref()))
+    }
+
+    /// Returns `true` if `self` ends with any of `suffixes` without
+    /// considering case. Returns `false` if `suffixes` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let file = UncasedStr::new("Photo.JPG");
+    /// assert!(file.ends_with_any([".png", ".jpg"]));
+    /// assert!(!file.ends_with_any([".png", ".gif"]));
+    /// assert!(!file.ends_with_any::<_, &str>([]));
+    /// ```
+    #[inline]
+    pub fn ends_with_any<I, S>(&self, suffixes: I) -> bool
+        where I: IntoIterator<Item = S>, S: AsRef<str>
+    {
+        let string = self.as_str();
+        suffixes.into_iter().any(|suffix| {
+            let suffix = suffix.as_ref();
+            string.len().checked_sub(suffix.len())
+                .and_then(|start| string.get(start..))
+                .map(|s| Self::new(s) == suffix)
+                .unwrap_or(false)
+        })
+    }
+
+    /// Returns `true` if `self` and `other` are equal after ASCII case-folding
+    /// and then mapping every character of both through `fold`.
+    ///
+    /// This allows characters other than ASCII letters to be considered equal.
+    /// Note that `fold` is called with characters that are already ASCII
+    /// lowercase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let dash = |c| if c == '_' { '-' } else { c };
+    /// assert!(UncasedStr::new("X-Y").eq_by("x_y".into(), dash));
+    /// assert!(UncasedStr::new("x_Y").eq_by("X-y".into(), dash));
+    /// assert!(!UncasedStr::new("X-Y").eq_by("x.y".into(), dash));
+    /// assert!(!UncasedStr::new("X-Y").eq_by("x-y-z".into(), dash));
+    /// ```
+    pub fn eq_by<F: FnMut(char) -> char>(&self, other: &UncasedStr, mut fold: F) -> bool {
+        let mut this = self.as_str().chars().map(|c| c.to_ascii_lowercase());
+        let mut other = other.as_str().chars().map(|c| c.to_ascii_lowercase());
+        loop {
+            match (this.next(), other.next()) {
+                (Some(a), Some(b)) if fold(a) == fold(b) => continue,
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns `true` if `self` is equal to any of `candidates` without
+    /// considering case; otherwise, returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let name = UncasedStr::new("content-type");
+    /// assert!(name.eq_any(["accept", "Content-Type", "host"]));
+    /// assert!(!name.eq_any(["accept", "host"]));
+    /// assert!(!name.eq_any(Vec::<String>::new()));
+    /// ```
+    #[inline]
+    pub fn eq_any<I: IntoIterator<Item = S>, S: AsRef<str>>(&self, candidates: I) -> bool {
+        candidates.into_iter().any(|candidate| self == candidate.as_ref())
+    }
+
+    /// Parses `self` as a boolean without considering case.
+    ///
+    /// Returns `Some(true)` for `true`, `yes`, `on`, and `1`, `Some(false)` for
+    /// `false`, `no`, `off`, and `0`, and `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// assert_eq!(UncasedStr::new("TRUE").parse_bool(), Some(true));
+    /// assert_eq!(UncasedStr::new("Off").parse_bool(), Some(false));
+    /// assert_eq!(UncasedStr::new("maybe").parse_bool(), None);
+    /// ```
+    pub fn parse_bool(&self) -> Option<bool> {
+        if self.eq_any(["true", "yes", "on", "1"]) {
+            Some(true)
+        } else if self.eq_any(["false", "no", "off", "0"]) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `self` matches the glob `pattern`, where `*` matches
+    /// any sequence of characters, `?` matches any single character, and all
+    /// other characters match any casing of themselves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let file = UncasedStr::new("Index.HTML");
+    /// assert!(file.glob_match("index.*"));
+    /// assert!(file.glob_match("*.html"));
+    /// assert!(file.glob_match("?NDEX.*"));
+    /// assert!(!file.glob_match("index.htm"));
+    /// assert!(!file.glob_match("*.css"));
+    /// ```
+    pub fn glob_match(&self, pattern: &str) -> bool {
+        let string = self.as_str();
+        let next_char = |s: &str, i: usize| s[i..].chars().next();
+
+        // Byte indices into `string` and `pattern`, and the position to resume
+        // from, if any, when backtracking to the most recent `*`.
+        let (mut s, mut p) = (0, 0);
+        let mut backtrack: Option<(usize, usize)> = None;
+        while let Some(c) = next_char(string, s) {
+            match next_char(pattern, p) {
+                Some('*') => {
+                    p += 1;
+                    backtrack = Some((s, p));
+                    continue;
+                }
+                Some(pc) if pc == '?' || pc.eq_ignore_ascii_case(&c) => {
+                    s += c.len_utf8();
+                    p += pc.len_utf8();
+                    continue;
+                }
+                _ => {}
+            }
+
+            // Mismatch: let the last `*` consume one more character, if any.
+            match backtrack {
+                Some((star_s, star_p)) => {
+                    let skipped = next_char(string, star_s).map_or(0, char::len_utf8);
+                    s = star_s + skipped;
+                    p = star_p;
+                    backtrack = Some((s, p));
+                }
+                None => return false,
+            }
+        }
+
+        pattern[p..].chars().all(|c| c == '*')
+    }
+
+    /// Returns `true` if `self` and `other` are equal without considering case
+    /// after removing every character for which `ignore` returns `true` from
+    /// both.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let separator = |c| c == '-' || c == ' ';
+    /// assert!(UncasedStr::new("AB-CD").eq_ignoring("ab cd".into(), separator));
+    /// assert!(UncasedStr::new("-abcd-").eq_ignoring("A B C D".into(), separator));
+    /// assert!(!UncasedStr::new("AB-CD").eq_ignoring("ab_cd".into(), separator));
+    /// ```
+    pub fn eq_ignoring<F: Fn(char) -> bool>(&self, other: &UncasedStr, ignore: F) -> bool {
+        let this = self.as_str().chars().filter(|&c| !ignore(c));
+        let other = other.as_str().chars().filter(|&c| !ignore(c));
+        this.map(|c| c.to_ascii_lowercase()).eq(other.map(|c| c.to_ascii_lowercase()))
+    }
+
+    /// Returns `true` if `self` and `other` are equal HTTP header names: that
+    /// is, if they are equal without considering case, with `-` and `_`
+    /// treated as equivalent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let name = UncasedStr::new("X-Forwarded_For");
+    /// assert!(name.eq_header_name("x-forwarded-for"));
+    /// assert!(name.eq_header_name("X_FORWARDED_FOR"));
+    /// assert!(!name.eq_header_name("x-forwarded-host"));
+    /// assert!(!name.eq_header_name("xforwardedfor"));
+    /// ```
+    #[inline]
+    pub fn eq_header_name(&self, other: &str) -> bool {
+        self.eq_by(other.into(), |c| if c == '_' { '-' } else { c })
+    }
+
+    /// Returns the longest of `prefixes` that `self` starts with, without
+    /// considering case, or `None` if `self` starts with none of them. Ties are
+    /// broken in favor of the first such prefix in `prefixes`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let routes = ["/api", "/API/v1", "/"];
+    /// let path = UncasedStr::new("/api/v1/users");
+    /// assert_eq!(path.longest_prefix(&routes), Some(&"/API/v1"));
+    /// assert_eq!(UncasedStr::new("/Api").longest_prefix(&routes), Some(&"/api"));
+    /// assert_eq!(UncasedStr::new("api").longest_prefix(&routes), None);
+    /// ```
+    pub fn longest_prefix<'a, S: AsRef<str>>(&self, prefixes: &'a [S]) -> Option<&'a S> {
+        prefixes.iter()
+            .filter(|prefix| self.starts_with(prefix.as_ref()))
+            .fold(None, |longest: Option<&S>, prefix| match longest {
+                Some(longest) if longest.as_ref().len() >= prefix.as_ref().len() => Some(longest),
+                _ => Some(prefix),
+            })
+    }
+
+    /// Returns `true` if the first character of `self` is any casing of `c`;
+    /// otherwise, including when `self` is empty, returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// assert!(UncasedStr::new("Host").starts_with_char('h'));
+    /// assert!(UncasedStr::new("host").starts_with_char('H'));
+    /// assert!(UncasedStr::new("èe").starts_with_char('è'));
+    /// assert!(!UncasedStr::new("accept").starts_with_char('h'));
+    /// assert!(!UncasedStr::new("").starts_with_char('h'));
+    /// ```
+    #[inline]
+    pub fn starts_with_char(&self, c: char) -> bool {
+        self.as_str()
+            .chars()
+            .next()
+            .map(|first| first.eq_ignore_ascii_case(&c))
+            .unwrap_or(false)
+    }
+
+    /// Returns `self` with all leading occurrences of any casing of `c`
+    /// removed. The case of the remaining characters is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let s = UncasedStr::new("AaXyz");
+    /// assert_eq!(s.trim_start_matches_char('a').as_str(), "Xyz");
+    /// assert_eq!(s.trim_start_matches_char('A').as_str(), "Xyz");
+    /// assert_eq!(s.trim_start_matches_char('x').as_str(), "AaXyz");
+    /// assert_eq!(UncasedStr::new("//a/b").trim_start_matches_char('/'), "a/b");
+    /// ```
+    #[inline]
+    pub fn trim_start_matches_char(&self, c: char) -> &UncasedStr {
+        UncasedStr::new(self.as_str().trim_start_matches(|x: char| x.eq_ignore_ascii_case(&c)))
+    }
+
+    /// Returns `self` with all trailing occurrences of any casing of `c`
+    /// removed. The case of the remaining characters is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let s = UncasedStr::new("XyzaA");
+    /// assert_eq!(s.trim_end_matches_char('a').as_str(), "Xyz");
+    /// assert_eq!(s.trim_end_matches_char('A').as_str(), "Xyz");
+    /// assert_eq!(UncasedStr::new("a/b//").trim_end_matches_char('/'), "a/b");
+    /// ```
+    #[inline]
+    pub fn trim_end_matches_char(&self, c: char) -> &UncasedStr {
+        UncasedStr::new(self.as_str().trim_end_matches(|x: char| x.eq_ignore_ascii_case(&c)))
+    }
+
+    /// Splits `self` on the first occurrence of any casing of `c`, returning
+    /// the parts before and after it, or `None` if `self` doesn't contain `c`.
+    /// The case of both parts is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let (key, val) = UncasedStr::new("Key=Val").split_once_char('=').unwrap();
+    /// assert_eq!(key.as_str(), "Key");
+    /// assert_eq!(val.as_str(), "Val");
+    ///
+    /// let (left, right) = UncasedStr::new("fooXbar").split_once_char('x').unwrap();
+    /// assert_eq!(left.as_str(), "foo");
+    /// assert_eq!(right.as_str(), "bar");
+    ///
+    /// assert!(UncasedStr::new("Key").split_once_char('=').is_none());
+    /// ```
+    #[inline]
+    pub fn split_once_char(&self, c: char) -> Option<(&UncasedStr, &UncasedStr)> {
+        let string = self.as_str();
+        let (i, found) = string.char_indices().find(|(_, x)| x.eq_ignore_ascii_case(&c))?;
+        let (left, right) = (&string[..i], &string[i + found.len_utf8()..]);
+        Some((UncasedStr::new(left), UncasedStr::new(right)))
+    }
+
+    /// Returns `true` if `self` and `other` are equal without considering
+    /// case, taking time dependent only on their lengths.
+    ///
+    /// Unlike `==`, this method does not return early on the first mismatched
+    /// character: the case-folded bytes are compared using the [`subtle`]
+    /// crate's `ConstantTimeEq`, making it suitable for comparing secrets such
+    /// as tokens. If the lengths differ, returns `false` immediately.
+    ///
+    /// [`subtle`]: https://crates.io/crates/subtle
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let token = UncasedStr::new("Secret-Token");
+    /// assert!(token.ct_eq("secret-token".into()));
+    /// assert!(!token.ct_eq("secret-tokem".into()));
+    /// assert!(!token.ct_eq("secret".into()));
+    /// ```
+    #[cfg(feature = "subtle")]
+    #[cfg_attr(nightly, doc(cfg(feature = "subtle")))]
+    pub fn ct_eq(&self, other: &UncasedStr) -> bool {
+        // Branch-free ASCII lowercasing: sets the `0x20` bit iff `b` is in
+        // `A..=Z`.
+        fn fold(b: u8) -> u8 {
+            b | ((b.wrapping_sub(b'A') < 26) as u8) << 5
+        }
+
+        let (a, b) = (self.as_str().as_bytes(), other.as_str().as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+
+        use subtle::{Choice, ConstantTimeEq};
+
+        let eq = a.iter().zip(b)
+            .fold(Choice::from(1), |eq, (&x, &y)| eq & fold(x).ct_eq(&fold(y)));
+        eq.into()
+    }
+
+    /// Splits `self` into the prefix preceding its trailing run of ASCII
+    /// digits and, if there is one, that run of digits. Both parts preserve
+    /// case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let (stem, digits) = UncasedStr::new("ABC123").split_numeric_suffix();
+    /// assert_eq!(stem.as_str(), "ABC");
+    /// assert_eq!(digits.unwrap().as_str(), "123");
+    ///
+    /// let (stem, digits) = UncasedStr::new("plain").split_numeric_suffix();
+    /// assert_eq!(stem.as_str(), "plain");
+    /// assert!(digits.is_none());
+    ///
+    /// let (stem, digits) = UncasedStr::new("999").split_numeric_suffix();
+    /// assert!(stem.is_empty());
+    /// assert_eq!(digits.unwrap().as_str(), "999");
+    /// ```
+    #[inline]
+    pub fn split_numeric_suffix(&self) -> (&UncasedStr, Option<&UncasedStr>) {
+        let stem = self.as_str().trim_end_matches(|c: char| c.is_ascii_digit());
+        match stem.len() {
+            len if len == self.len() => (self, None),
+            len => (&self[..len], Some(&self[len..])),
+        }
+    }
+
+    /// Returns the number of whitespace-separated words in `self`, as split by
+    /// [`str::split_whitespace()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// assert_eq!(UncasedStr::new("  Foo  bar BAZ ").count_words(), 3);
+    /// assert_eq!(UncasedStr::new(" \t\n").count_words(), 0);
     /// ```
-    #[inline(always)]
-    pub fn len(&self) -> usize {
-        self.as_str().len()
+    #[inline]
+    pub fn count_words(&self) -> usize {
+        self.as_str().split_whitespace().count()
     }
 
-    /// Returns `true` if `self` has a length of zero bytes.
+    /// Returns an iterator over the substrings of `self` separated by ASCII
+    /// whitespace, as by [`str::split_ascii_whitespace()`]. Non-ASCII
+    /// whitespace is not treated as a separator.
     ///
-    /// # Examples
+    /// # Example
     ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let words: Vec<_> = UncasedStr::new(" Foo\tbar\u{3000}BAZ ")
+    ///     .split_ascii_whitespace()
+    ///     .collect();
+    ///
+    /// assert_eq!(words, ["foo", "BAR\u{3000}baz"]);
     /// ```
+    #[inline]
+    pub fn split_ascii_whitespace(&self) -> impl Iterator<Item = &UncasedStr> {
+        self.as_str().split_ascii_whitespace().map(UncasedStr::new)
+    }
+
+    /// Returns an iterator over all overlapping windows of `n` characters in
+    /// `self`, in order. If `self` contains fewer than `n` characters, the
+    /// iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
     /// use uncased::UncasedStr;
     ///
-    /// let s = UncasedStr::new("");
-    /// assert!(s.is_empty());
+    /// let mut windows = UncasedStr::new("ABC").char_windows(2);
+    /// assert_eq!(windows.next().unwrap(), "ab");
+    /// assert_eq!(windows.next().unwrap(), "bc");
+    /// assert_eq!(windows.next(), None);
     ///
-    /// let s = UncasedStr::new("not empty");
-    /// assert!(!s.is_empty());
+    /// let windows: Vec<_> = UncasedStr::new("Bèe").char_windows(2).collect();
+    /// assert_eq!(windows, ["bè", "èe"]);
+    /// ```
+    pub fn char_windows(&self, n: usize) -> impl Iterator<Item = &UncasedStr> {
+        assert!(n != 0, "window size must be non-zero");
+
+        let string = self.as_str();
+        let boundaries = string.char_indices()
+            .map(|(i, _)| i)
+            .chain(core::iter::once(string.len()));
+
+        let starts = string.char_indices().map(|(i, _)| i);
+        starts.zip(boundaries.skip(n)).map(move |(start, end)| &self[start..end])
+    }
+
+    /// Returns the 64-bit FxHash of the ASCII lowercase form of `self`.
+    ///
+    /// The result is that of writing the lowercased bytes to a fresh 64-bit
+    /// `FxHasher` from `rustc-hash` 1.x and calling `finish()`, except that
+    /// words are always read as little-endian.
+    ///
+    /// Unlike the [`Hash`] implementation, the result is independent of any
+    /// `Hasher` and is stable across runs and platforms, making it suitable
+    /// for deriving shard indices. Strings that are equal without considering
+    /// case have equal hashes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let hash = UncasedStr::new("Abc").fxhash();
+    /// assert_eq!(hash, UncasedStr::new("ABC").fxhash());
+    /// assert_eq!(hash, UncasedStr::new("abc").fxhash());
+    /// assert_ne!(hash, UncasedStr::new("abd").fxhash());
+    /// ```
+    #[cfg(feature = "fxhash")]
+    #[cfg_attr(nightly, doc(cfg(feature = "fxhash")))]
+    pub fn fxhash(&self) -> u64 {
+        const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+        fn add(hash: u64, word: u64) -> u64 {
+            (hash.rotate_left(5) ^ word).wrapping_mul(SEED)
+        }
+
+        // Reads `bytes`, lowercased, as a little-endian integer.
+        fn read(bytes: &[u8]) -> u64 {
+            bytes.iter().rev().fold(0, |w, b| (w << 8) | b.to_ascii_lowercase() as u64)
+        }
+
+        let (mut hash, mut bytes) = (0, self.as_str().as_bytes());
+        while bytes.len() >= 8 {
+            hash = add(hash, read(&bytes[..8]));
+            bytes = &bytes[8..];
+        }
+
+        if bytes.len() >= 4 {
+            hash = add(hash, read(&bytes[..4]));
+            bytes = &bytes[4..];
+        }
+
+        if bytes.len() >= 2 {
+            hash = add(hash, read(&bytes[..2]));
+            bytes = &bytes[2..];
+        }
+
+        if let Some(&byte) = bytes.first() {
+            hash = add(hash, byte.to_ascii_lowercase() as u64);
+        }
+
+        hash
+    }
+
+    /// Returns the Levenshtein edit distance between `self` and `other`,
+    /// ignoring ASCII case.
+    ///
+    /// The distance is the minimum number of single-character insertions,
+    /// deletions, or substitutions required to transform `self` into `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let kitten = UncasedStr::new("Kitten");
+    /// assert_eq!(kitten.levenshtein("SITTING".into()), 3);
+    /// assert_eq!(kitten.levenshtein("kITTEN".into()), 0);
+    /// assert_eq!(kitten.levenshtein("".into()), 6);
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    #[cfg_attr(nightly, doc(cfg(feature = "fuzzy")))]
+    pub fn levenshtein(&self, other: &UncasedStr) -> usize {
+        let other_len = other.as_str().chars().count();
+        let mut row: alloc::vec::Vec<usize> = (0..=other_len).collect();
+
+        for (i, a) in self.as_str().chars().enumerate() {
+            // `diagonal` is the distance between the previous prefixes.
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, b) in other.as_str().chars().enumerate() {
+                let cost = if a.eq_ignore_ascii_case(&b) { 0 } else { 1 };
+                let distance = (diagonal + cost).min(row[j] + 1).min(row[j + 1] + 1);
+                diagonal = row[j + 1];
+                row[j + 1] = distance;
+            }
+        }
+
+        row[other_len]
+    }
+
+    /// Returns an iterator over the substrings of `self` separated by any
+    /// casing of `delim`, where each substring but the last includes the
+    /// delimiter that terminates it. The substrings preserve case.
+    ///
+    /// If `delim` is empty, the iterator yields `self` exactly once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let parts: Vec<_> = UncasedStr::new("a;b;c").split_keep_sep(";").collect();
+    /// assert_eq!(parts, ["a;", "b;", "c"]);
+    ///
+    /// let parts: Vec<_> = UncasedStr::new("1And2AND").split_keep_sep("and").collect();
+    /// assert_eq!(parts[0].as_str(), "1And");
+    /// assert_eq!(parts[1].as_str(), "2AND");
+    /// assert_eq!(parts[2].as_str(), "");
+    /// ```
+    pub fn split_keep_sep<'a>(&'a self, delim: &'a str) -> impl Iterator<Item = &'a UncasedStr> {
+        let mut rest = Some(self.as_str());
+        core::iter::from_fn(move || {
+            let string = rest?;
+            match find(string, delim).filter(|_| !delim.is_empty()) {
+                Some(i) => {
+                    let (segment, remaining) = string.split_at(i + delim.len());
+                    rest = Some(remaining);
+                    Some(segment)
+                }
+                None => rest.take(),
+            }
+        }).map(UncasedStr::new)
+    }
+
+    /// Returns an iterator over the substrings of `self` separated by any
+    /// casing of `delim`, in reverse order. The substrings preserve case.
+    ///
+    /// If `delim` is empty, the iterator yields `self` exactly once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let parts: Vec<_> = UncasedStr::new("a.b.c").rsplit(".").collect();
+    /// assert_eq!(parts, ["c", "b", "a"]);
+    ///
+    /// let parts: Vec<_> = UncasedStr::new("OneXtwoxThree").rsplit("x").collect();
+    /// assert_eq!(parts, ["three", "two", "one"]);
+    /// assert_eq!(parts[0].as_str(), "Three");
+    /// ```
+    pub fn rsplit<'a>(&'a self, delim: &'a str) -> impl Iterator<Item = &'a UncasedStr> {
+        let mut rest = Some(self.as_str());
+        core::iter::from_fn(move || {
+            let string = rest?;
+            match rfind(string, delim).filter(|_| !delim.is_empty()) {
+                Some(i) => {
+                    rest = Some(&string[..i]);
+                    Some(&string[(i + delim.len())..])
+                }
+                None => rest.take(),
+            }
+        }).map(UncasedStr::new)
+    }
+
+    /// Returns an iterator over the non-overlapping matches of any casing of
+    /// `pat` in `self`, in reverse order, along with the byte index at which
+    /// each match starts. The matches preserve case.
+    ///
+    /// If `pat` is empty, the iterator yields nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let matches: Vec<_> = UncasedStr::new("aXaXa").rmatch_indices("x")
+    ///     .map(|(i, m)| (i, m.as_str()))
+    ///     .collect();
+    ///
+    /// assert_eq!(matches, [(3, "X"), (1, "X")]);
+    /// ```
+    pub fn rmatch_indices<'a>(&'a self, pat: &'a str) -> impl Iterator<Item = (usize, &'a UncasedStr)> {
+        let mut end = self.len();
+        core::iter::from_fn(move || {
+            if pat.is_empty() {
+                return None;
+            }
+
+            let start = rfind(&self.as_str()[..end], pat)?;
+            end = start;
+            Some((start, &self[start..(start + pat.len())]))
+        })
+    }
+
+    /// Returns an iterator over the non-overlapping matches of any casing of
+    /// `pat` in `self`, in reverse order. The matches preserve case.
+    ///
+    /// If `pat` is empty, the iterator yields nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let matches: Vec<_> = UncasedStr::new("Abcabc").rmatches("ABC")
+    ///     .map(|m| m.as_str())
+    ///     .collect();
+    ///
+    /// assert_eq!(matches, ["abc", "Abc"]);
     /// ```
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.as_str().is_empty()
+    pub fn rmatches<'a>(&'a self, pat: &'a str) -> impl Iterator<Item = &'a UncasedStr> {
+        self.rmatch_indices(pat).map(|(_, m)| m)
     }
 
-    /// Returns `true` if `self` starts with any casing of the string `string`;
-    /// otherwise, returns `false`.
+    /// Returns the ASCII lowercase form of `self`, allocating only if `self`
+    /// contains ASCII uppercase characters.
     ///
     /// # Example
     ///
     /// ```rust
+    /// use std::borrow::Cow;
     /// use uncased::UncasedStr;
     ///
-    /// let uncased_str = UncasedStr::new("MoOO");
-    /// assert!(uncased_str.starts_with("moo"));
-    /// assert!(uncased_str.starts_with("MOO"));
-    /// assert!(uncased_str.starts_with("MOOO"));
-    /// assert!(!uncased_str.starts_with("boo"));
+    /// let lower = UncasedStr::new("content-type").to_ascii_lowercase_cow();
+    /// assert!(matches!(lower, Cow::Borrowed("content-type")));
     ///
-    /// let uncased_str = UncasedStr::new("Bèe");
-    /// assert!(!uncased_str.starts_with("Be"));
-    /// assert!(uncased_str.starts_with("Bè"));
-    /// assert!(uncased_str.starts_with("Bè"));
-    /// assert!(uncased_str.starts_with("bèe"));
-    /// assert!(uncased_str.starts_with("BèE"));
+    /// let lower = UncasedStr::new("Content-Type").to_ascii_lowercase_cow();
+    /// assert!(matches!(lower, Cow::Owned(_)));
+    /// assert_eq!(lower, "content-type");
     /// ```
-    #[inline(always)]
-    pub fn starts_with(&self, string: &str) -> bool {
-        self.as_str()
-            .get(..string.len())
-            .map(|s| Self::new(s) == string)
-            .unwrap_or(false)
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(nightly, doc(cfg(feature = "alloc")))]
+    pub fn to_ascii_lowercase_cow(&self) -> Cow<'_, str> {
+        if self.as_str().bytes().all(|b| !b.is_ascii_uppercase()) {
+            Cow::Borrowed(self.as_str())
+        } else {
+            Cow::Owned(self.as_str().to_ascii_lowercase())
+        }
+    }
+
+    /// Returns `self`, preserving case, with characters escaped as by
+    /// [`str::escape_debug()`]. This is useful for safely logging values that
+    /// may contain control characters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedStr;
+    ///
+    /// let value = UncasedStr::new("Text/Plain\t; charset=\"utf-8\"\n");
+    /// assert_eq!(value.escape_debug(), r#"Text/Plain\t; charset=\"utf-8\"\n"#);
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(nightly, doc(cfg(feature = "alloc")))]
+    pub fn escape_debug(&self) -> String {
+        self.as_str().escape_debug().collect()
     }
 
     /// Converts a `Box<UncasedStr>` into an `Uncased` without copying or
@@ -167,6 +1131,30 @@ impl UncasedStr {
     }
 }
 
+/// Returns `true` if `haystack` contains any casing of `needle` starting at
+/// byte index `i`.
+#[inline]
+fn matches_at(haystack: &str, needle: &str, i: usize) -> bool {
+    haystack.is_char_boundary(i) && haystack.as_bytes()
+        .get(i..(i + needle.len()))
+        .map(|bytes| bytes.eq_ignore_ascii_case(needle.as_bytes()))
+        .unwrap_or(false)
+}
+
+/// Returns the byte index of the first match of any casing of `needle` in
+/// `haystack`, if there is one.
+fn find(haystack: &str, needle: &str) -> Option<usize> {
+    let last = haystack.len().checked_sub(needle.len())?;
+    (0..=last).find(|&i| matches_at(haystack, needle, i))
+}
+
+/// Returns the byte index of the last match of any casing of `needle` in
+/// `haystack`, if there is one.
+fn rfind(haystack: &str, needle: &str) -> Option<usize> {
+    let last = haystack.len().checked_sub(needle.len())?;
+    (0..=last).rev().find(|&i| matches_at(haystack, needle, i))
+}
+
 impl<'a> From<&'a str> for &'a UncasedStr {
     #[inline(always)]
     fn from(string: &'a str) -> &'a UncasedStr {
@@ -202,6 +1190,36 @@ impl fmt::Display for UncasedStr {
     }
 }
 
+/// A `fmt::Write` adapter that ASCII lowercases everything written through it.
+///
+/// Since the escape sequences emitted by `Debug` and `ascii::escape_default()`
+/// never contain uppercase letters, writing the escaped form of a string
+/// through this adapter yields exactly the escaped form of its lowercase
+/// equivalent.
+pub(crate) struct LowercaseWriter<'a, 'b>(pub &'a mut fmt::Formatter<'b>);
+
+impl fmt::Write for LowercaseWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.0.write_char(c.to_ascii_lowercase()))
+    }
+}
+
+/// Formats `string` as `name("String" /* = "string" */)`, making both the
+/// preserved and case-folded forms visible.
+pub(crate) fn debug_fmt(name: &str, string: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    use fmt::Write;
+
+    write!(f, "{}({:?} /* = ", name, string)?;
+    write!(LowercaseWriter(f), "{:?}", string)?;
+    f.write_str(" */)")
+}
+
+impl fmt::Debug for UncasedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_fmt("UncasedStr", self.as_str(), f)
+    }
+}
+
 macro_rules! impl_partial_eq {
     ($other:ty $([$o_i:ident])? = $this:ty $([$t_i:ident])?) => (
         impl PartialEq<$other> for $this {
@@ -225,6 +1243,31 @@ impl_partial_eq!(UncasedStr [as_str] = &str);
 
 #[cfg(feature = "alloc")] impl_partial_eq!(UncasedStr [as_str] = String [as_str] );
 
+#[cfg(feature = "alloc")] impl_partial_eq!(&String [as_str] = UncasedStr [as_str]);
+
+#[cfg(feature = "alloc")] impl_partial_eq!(UncasedStr [as_str] = &String [as_str]);
+
+impl PartialEq<[u8]> for UncasedStr {
+    #[inline(always)]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_str().as_bytes().eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&[u8]> for UncasedStr {
+    #[inline(always)]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.as_str().as_bytes().eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<[u8]> for &UncasedStr {
+    #[inline(always)]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_str().as_bytes().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Eq for UncasedStr { }
 
 macro_rules! impl_partial_ord {
@@ -264,7 +1307,7 @@ impl_partial_ord!(UncasedStr >< str);
 impl Hash for UncasedStr {
     #[inline(always)]
     fn hash<H: Hasher>(&self, hasher: &mut H) {
-        self.0.bytes().for_each(|b| hasher.write_u8(b.to_ascii_lowercase()));
+        crate::write_folded(self.as_str(), hasher)
     }
 }
 
@@ -277,7 +1320,149 @@ impl From<&UncasedStr> for Arc<UncasedStr> {
         // pointer passed to `from_raw()` is clearly obtained by calling
         // `into_raw()`. This fulfills the safety requirements of `from_raw()`.
         let arc: Arc<str> = Arc::from(&v.0);
-        let raw = Arc::into_raw(arc) as *const str as *const UncasedStr;
+        let raw = Arc::into_raw(arc) as *const UncasedStr;
         unsafe { Arc::from_raw(raw) }
     }
 }
+
+/// An error returned when converting an [`UncasedStr`] into a `char` fails
+/// because it is empty or contains more than one `char`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use uncased::UncasedStr;
+///
+/// let error = char::try_from(UncasedStr::new("ab")).unwrap_err();
+/// assert_eq!(error.to_string(), "too many characters in string");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCharError {
+    empty: bool,
+}
+
+impl fmt::Display for ParseCharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.empty {
+            f.write_str("cannot parse char from empty string")
+        } else {
+            f.write_str("too many characters in string")
+        }
+    }
+}
+
+impl TryFrom<&UncasedStr> for char {
+    type Error = ParseCharError;
+
+    #[inline]
+    fn try_from(string: &UncasedStr) -> Result<char, Self::Error> {
+        let mut chars = string.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            (first, _) => Err(ParseCharError { empty: first.is_none() }),
+        }
+    }
+}
+
+/// A cursor over an [`UncasedStr`] that consumes case-insensitive tokens from
+/// the front of the string without copying.
+///
+/// # Example
+///
+/// ```rust
+/// use uncased::{UncasedCursor, UncasedStr};
+///
+/// let mut cursor = UncasedCursor::new(UncasedStr::new("Bearer abc"));
+/// assert!(!cursor.eat("basic "));
+/// assert!(cursor.eat("bearer "));
+/// assert_eq!(cursor.rest().as_str(), "abc");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct UncasedCursor<'a> {
+    rest: &'a UncasedStr,
+}
+
+impl<'a> UncasedCursor<'a> {
+    /// Creates a new cursor positioned at the start of `string`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::{UncasedCursor, UncasedStr};
+    ///
+    /// let cursor = UncasedCursor::new(UncasedStr::new("Hello"));
+    /// assert_eq!(cursor.rest(), "hello");
+    /// ```
+    #[inline(always)]
+    pub fn new(string: &'a UncasedStr) -> UncasedCursor<'a> {
+        UncasedCursor { rest: string }
+    }
+
+    /// Returns the remaining, unconsumed portion of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::{UncasedCursor, UncasedStr};
+    ///
+    /// let mut cursor = UncasedCursor::new(UncasedStr::new("Hello"));
+    /// cursor.eat("HE");
+    /// assert_eq!(cursor.rest().as_str(), "llo");
+    /// ```
+    #[inline(always)]
+    pub fn rest(&self) -> &'a UncasedStr {
+        self.rest
+    }
+
+    /// If the remaining string starts with any casing of `token`, advances
+    /// past it and returns `true`. Otherwise, returns `false` and leaves the
+    /// cursor unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::{UncasedCursor, UncasedStr};
+    ///
+    /// let mut cursor = UncasedCursor::new(UncasedStr::new("GET /"));
+    /// assert!(!cursor.eat("post"));
+    /// assert!(cursor.eat("get"));
+    /// assert!(cursor.eat(" "));
+    /// assert_eq!(cursor.rest(), "/");
+    /// ```
+    #[inline]
+    pub fn eat(&mut self, token: &str) -> bool {
+        if self.rest.starts_with(token) {
+            self.rest = &self.rest[token.len()..];
+            return true;
+        }
+
+        false
+    }
+
+    /// Advances past the longest prefix of the remaining string whose
+    /// characters all satisfy `pred`, returning the consumed prefix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::{UncasedCursor, UncasedStr};
+    ///
+    /// let mut cursor = UncasedCursor::new(UncasedStr::new("Token123 rest"));
+    /// let word = cursor.eat_while(|c| c.is_ascii_alphanumeric());
+    /// assert_eq!(word.as_str(), "Token123");
+    /// assert_eq!(cursor.rest().as_str(), " rest");
+    /// ```
+    #[inline]
+    pub fn eat_while<F: FnMut(char) -> bool>(&mut self, mut pred: F) -> &'a UncasedStr {
+        let string = self.rest.as_str();
+        let end = string.char_indices()
+            .find(|&(_, c)| !pred(c))
+            .map(|(i, _)| i)
+            .unwrap_or(string.len());
+
+        let (eaten, rest) = (&self.rest[..end], &self.rest[end..]);
+        self.rest = rest;
+        eaten
+    }
+}
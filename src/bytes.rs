@@ -0,0 +1,223 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::fmt;
+
+/// A cost-free reference to an uncased (case-insensitive, case-preserving)
+/// ASCII byte string.
+///
+/// This is the byte-level equivalent of [`UncasedStr`](crate::UncasedStr):
+/// bytes need not be valid UTF-8, and comparison (including ordering,
+/// equality, and hashing) is ASCII case-insensitive. An `UncasedBytes` hashes
+/// identically to an `UncasedStr` with the same contents.
+///
+/// ```rust
+/// use uncased::UncasedBytes;
+///
+/// let bytes: &UncasedBytes = b"Content-Type".as_ref().into();
+/// assert_eq!(bytes, UncasedBytes::new(b"content-type"));
+/// ```
+#[repr(transparent)]
+pub struct UncasedBytes([u8]);
+
+impl UncasedBytes {
+    /// Cost-free conversion from an `&[u8]` reference to an `UncasedBytes`.
+    ///
+    /// This is a `const fn` on Rust 1.56+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedBytes;
+    ///
+    /// let bytes = UncasedBytes::new(b"Hello!");
+    /// assert_eq!(bytes, UncasedBytes::new(b"hello!"));
+    /// assert_eq!(bytes, UncasedBytes::new(b"HeLLo!"));
+    /// ```
+    #[inline(always)]
+    #[cfg(not(const_fn_transmute))]
+    pub fn new(bytes: &[u8]) -> &UncasedBytes {
+        // This is a `newtype`-like transformation. `repr(transparent)` ensures
+        // that this is safe and correct.
+        unsafe { &*(bytes as *const [u8] as *const UncasedBytes) }
+    }
+
+    /// Cost-free conversion from an `&[u8]` reference to an `UncasedBytes`.
+    ///
+    /// This is a `const fn` on Rust 1.56+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedBytes;
+    ///
+    /// let bytes = UncasedBytes::new(b"Hello!");
+    /// assert_eq!(bytes, UncasedBytes::new(b"hello!"));
+    /// assert_eq!(bytes, UncasedBytes::new(b"HeLLo!"));
+    /// ```
+    #[inline(always)]
+    #[cfg(const_fn_transmute)]
+    pub const fn new(bytes: &[u8]) -> &UncasedBytes {
+        // This is a `newtype`-like transformation. `repr(transparent)` ensures
+        // that this is safe and correct.
+        unsafe { core::mem::transmute(bytes) }
+    }
+
+    /// Returns `self` as an `&[u8]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedBytes;
+    ///
+    /// let bytes = UncasedBytes::new(b"Hello!");
+    /// assert_eq!(bytes.as_bytes(), b"Hello!");
+    /// assert_ne!(bytes.as_bytes(), b"hELLo!");
+    /// ```
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the length, in bytes, of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedBytes;
+    ///
+    /// let bytes = UncasedBytes::new(b"Hello!");
+    /// assert_eq!(bytes.len(), 6);
+    /// ```
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns `true` if `self` has a length of zero bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedBytes;
+    ///
+    /// assert!(UncasedBytes::new(b"").is_empty());
+    /// assert!(!UncasedBytes::new(b"not empty").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+
+    /// Returns `true` if `self` starts with any casing of `bytes`; otherwise,
+    /// returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedBytes;
+    ///
+    /// let bytes = UncasedBytes::new(b"MoOO");
+    /// assert!(bytes.starts_with(b"moo"));
+    /// assert!(bytes.starts_with(b"MOOO"));
+    /// assert!(!bytes.starts_with(b"boo"));
+    /// assert!(!bytes.starts_with(b"mooooo"));
+    /// ```
+    #[inline]
+    pub fn starts_with(&self, bytes: &[u8]) -> bool {
+        self.as_bytes()
+            .get(..bytes.len())
+            .map(|prefix| prefix.eq_ignore_ascii_case(bytes))
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if `self` contains any casing of `bytes`; otherwise,
+    /// returns `false`. An empty `bytes` is contained in every string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedBytes;
+    ///
+    /// let bytes = UncasedBytes::new(b"text/HTML; charset=utf-8");
+    /// assert!(bytes.contains(b"html"));
+    /// assert!(bytes.contains(b"CHARSET"));
+    /// assert!(!bytes.contains(b"json"));
+    /// assert!(bytes.contains(b""));
+    /// ```
+    #[inline]
+    pub fn contains(&self, bytes: &[u8]) -> bool {
+        bytes.is_empty() || self.as_bytes()
+            .windows(bytes.len())
+            .any(|window| window.eq_ignore_ascii_case(bytes))
+    }
+}
+
+impl<'a> From<&'a [u8]> for &'a UncasedBytes {
+    #[inline(always)]
+    fn from(bytes: &'a [u8]) -> &'a UncasedBytes {
+        UncasedBytes::new(bytes)
+    }
+}
+
+impl AsRef<[u8]> for UncasedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Displays bytes as an escaped byte string literal, e.g. `b"A\xff"`.
+struct ByteStr<'a>(&'a [u8]);
+
+impl fmt::Display for ByteStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        f.write_str("b\"")?;
+        for &byte in self.0 {
+            core::ascii::escape_default(byte).try_for_each(|b| f.write_char(b as char))?;
+        }
+
+        f.write_char('"')
+    }
+}
+
+impl fmt::Debug for UncasedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        write!(f, "UncasedBytes({} /* = ", ByteStr(&self.0))?;
+        write!(crate::borrowed::LowercaseWriter(f), "{}", ByteStr(&self.0))?;
+        f.write_str(" */)")
+    }
+}
+
+impl PartialEq for UncasedBytes {
+    #[inline(always)]
+    fn eq(&self, other: &UncasedBytes) -> bool {
+        self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
+    }
+}
+
+impl Eq for UncasedBytes { }
+
+impl PartialOrd for UncasedBytes {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &UncasedBytes) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UncasedBytes {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let self_bytes = self.0.iter().map(|b| b.to_ascii_lowercase());
+        let other_bytes = other.0.iter().map(|b| b.to_ascii_lowercase());
+        self_bytes.cmp(other_bytes)
+    }
+}
+
+impl Hash for UncasedBytes {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.0.iter().for_each(|b| hasher.write_u8(b.to_ascii_lowercase()));
+    }
+}
@@ -33,7 +33,19 @@
 //! * `alloc` (_default_) - enables the [`Uncased`] type
 //! * `with-serde` - enables (de)serializing of [`UncasedStr`] via `serde`
 //! * `with-serde-alloc` - enables `alloc`, (de)serializing of [`UncasedStr`]
-//!    and [`Uncased`] via `serde`
+//!   and [`Uncased`] via `serde`
+//! * `subtle` - enables constant-time comparison via `UncasedStr::ct_eq()`,
+//!   backed by [`subtle`](https://crates.io/crates/subtle)
+//! * `fuzzy` - enables `alloc`, edit distance via `UncasedStr::levenshtein()`
+//! * `fxhash` - enables stable, hasher-independent hashing via
+//!   `UncasedStr::fxhash()`
+//! * `smol_str` - enables the `UncasedSmol` type, which stores short strings
+//!   inline via [`smol_str`](https://crates.io/crates/smol_str)
+//!
+//! When `serde` support is enabled, the `serde_canonical` module can be used
+//! with `#[serde(with = "uncased::serde_canonical")]` to serialize values in
+//! their lowercase form, and the `serde_ascii` module can be used to reject
+//! strings containing non-ASCII characters during deserialization.
 //!
 //! This crate is `#![no_std]` compatible. By default, the `alloc` feature is
 //! enabled, which enables the [`Uncased`] type but requires `alloc` support. To
@@ -56,14 +68,27 @@
 
 #[cfg(feature = "serde")] mod serde;
 #[cfg(feature = "alloc")] mod owned;
+#[cfg(feature = "smol_str")] mod smol;
+#[cfg(feature = "serde")]
+#[cfg_attr(nightly, doc(cfg(feature = "serde")))]
+pub mod serde_canonical;
+#[cfg(feature = "serde")]
+#[cfg_attr(nightly, doc(cfg(feature = "serde")))]
+pub mod serde_ascii;
 #[cfg(test)] mod tests;
 mod borrowed;
+mod bytes;
 mod as_uncased;
 
 #[cfg(feature = "alloc")] pub use owned::Uncased;
-pub use borrowed::UncasedStr;
+#[cfg(feature = "smol_str")] pub use smol::UncasedSmol;
+pub use borrowed::{UncasedStr, UncasedCursor, ParseCharError};
+pub use bytes::UncasedBytes;
 pub use as_uncased::AsUncased;
 
+use core::cmp::Ordering;
+use core::hash::Hasher;
+
 /// Returns true if `s1` and `s2` are equal without considering case.
 ///
 /// That is, this function returns `s1.to_ascii_lowercase() ==
@@ -82,3 +107,53 @@ pub use as_uncased::AsUncased;
 pub fn eq<S1: AsRef<str>, S2: AsRef<str>>(s1: S1, s2: S2) -> bool {
     UncasedStr::new(s1.as_ref()) == UncasedStr::new(s2.as_ref())
 }
+
+/// Compares the slices `a` and `b` element-wise without considering case,
+/// then by length.
+///
+/// That is, this function orders `a` and `b` lexicographically, comparing
+/// each pair of elements as if by `UncasedStr::new(x).cmp(UncasedStr::new(y))`.
+///
+/// # Example
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// assert_eq!(uncased::cmp_slices(&["A", "b"], &["a", "C"]), Ordering::Less);
+/// assert_eq!(uncased::cmp_slices(&["A", "b"], &["a", "B"]), Ordering::Equal);
+/// assert_eq!(uncased::cmp_slices(&["A", "b"], &["a"]), Ordering::Greater);
+/// ```
+#[inline]
+pub fn cmp_slices<S1: AsRef<str>, S2: AsRef<str>>(a: &[S1], b: &[S2]) -> Ordering {
+    let a = a.iter().map(|s| UncasedStr::new(s.as_ref()));
+    let b = b.iter().map(|s| UncasedStr::new(s.as_ref()));
+    a.cmp(b)
+}
+
+/// Feeds the ASCII lowercase form of `s` into the hasher `h`.
+///
+/// This is exactly how [`UncasedStr`] implements [`Hash`](core::hash::Hash),
+/// so wrapper types can use this function to hash identically to an
+/// `UncasedStr` with the same contents.
+///
+/// # Example
+///
+/// ```rust
+/// use std::hash::{BuildHasher, Hash, Hasher};
+/// use std::collections::hash_map::RandomState;
+/// use uncased::UncasedStr;
+///
+/// let state = RandomState::new();
+///
+/// let mut hasher = state.build_hasher();
+/// uncased::write_folded("Content-Type", &mut hasher);
+/// let folded = hasher.finish();
+///
+/// let mut hasher = state.build_hasher();
+/// UncasedStr::new("CONTENT-TYPE").hash(&mut hasher);
+/// assert_eq!(folded, hasher.finish());
+/// ```
+#[inline]
+pub fn write_folded<H: Hasher>(s: &str, h: &mut H) {
+    s.bytes().for_each(|b| h.write_u8(b.to_ascii_lowercase()));
+}
@@ -1,7 +1,9 @@
-use alloc::borrow::{Cow, Borrow};
-use alloc::{string::String, boxed::Box};
+use alloc::borrow::{Cow, Borrow, BorrowMut};
+use alloc::{string::String, boxed::Box, rc::Rc, sync::Arc};
+#[cfg(try_reserve)]
+use alloc::collections::TryReserveError;
 
-use core::ops::Deref;
+use core::ops::{Deref, RangeBounds};
 use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
 use core::fmt;
@@ -11,7 +13,7 @@ use crate::{UncasedStr, AsUncased};
 /// An uncased (case-insensitive, case-preserving), owned _or_ borrowed ASCII
 /// string.
 #[cfg_attr(nightly, doc(cfg(feature = "alloc")))]
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Uncased<'s> {
     #[doc(hidden)]
     pub string: Cow<'s, str>
@@ -68,18 +70,114 @@ impl<'s> Uncased<'s> {
         Uncased { string: Cow::Owned(string) }
     }
 
-    /// Converts `self` into an owned `Uncased<'static>`, allocating if
-    /// necessary.
+    /// Creates a new, empty `Uncased` string without allocating.
     ///
     /// # Example
     ///
     /// ```rust
     /// use uncased::Uncased;
     ///
-    /// let foo = "foo".to_string();
-    /// let uncased = Uncased::from(foo.as_str());
-    /// let owned: Uncased<'static> = uncased.into_owned();
-    /// assert_eq!(owned, "foo");
+    /// static EMPTY: Uncased = Uncased::empty();
+    /// assert!(EMPTY.is_empty());
+    /// assert_eq!(EMPTY, "");
+    /// ```
+    #[inline(always)]
+    pub const fn empty() -> Uncased<'static> {
+        Uncased::from_borrowed("")
+    }
+
+    /// Decodes a UTF-16 encoded slice `v` into a new owned `Uncased` string,
+    /// returning an error if `v` contains any invalid data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let utf16: Vec<u16> = "Content-Type".encode_utf16().collect();
+    /// let uncased = Uncased::from_utf16(&utf16).unwrap();
+    /// assert_eq!(uncased, "content-type");
+    ///
+    /// let invalid = [0x0048, 0xD800, 0x0069];
+    /// assert!(Uncased::from_utf16(&invalid).is_err());
+    /// ```
+    #[inline]
+    pub fn from_utf16(v: &[u16]) -> Result<Uncased<'static>, alloc::string::FromUtf16Error> {
+        String::from_utf16(v).map(Uncased::from_owned)
+    }
+
+    /// Creates a new owned `Uncased` string from the characters in `iter`.
+    ///
+    /// Space for the string is reserved upfront using the lower bound of the
+    /// iterator's size hint, avoiding repeated reallocations for large inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let uncased = Uncased::collect_str("Content-Type".chars());
+    /// assert_eq!(uncased, "content-type");
+    /// assert_eq!(uncased.as_str(), "Content-Type");
+    /// ```
+    pub fn collect_str<I: IntoIterator<Item = char>>(iter: I) -> Uncased<'static> {
+        let iter = iter.into_iter();
+        let mut string = String::with_capacity(iter.size_hint().0);
+        iter.for_each(|c| string.push(c));
+        Uncased::from_owned(string)
+    }
+
+    /// Creates a new owned `Uncased` string by joining each key and value in
+    /// `pairs` with `kv_sep` and each pair with `pair_sep`. The case of all
+    /// keys and values is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let uncased = Uncased::from_parts(&[("A", "1"), ("B", "2")], "=", "; ");
+    /// assert_eq!(uncased.as_str(), "A=1; B=2");
+    /// assert_eq!(uncased, "a=1; b=2");
+    ///
+    /// let empty = Uncased::from_parts::<&str, &str>(&[], "=", "; ");
+    /// assert!(empty.is_empty());
+    /// ```
+    pub fn from_parts<K, V>(pairs: &[(K, V)], kv_sep: &str, pair_sep: &str) -> Uncased<'static>
+        where K: AsRef<str>, V: AsRef<str>
+    {
+        let mut string = String::new();
+        for (i, (key, value)) in pairs.iter().enumerate() {
+            if i > 0 {
+                string.push_str(pair_sep);
+            }
+
+            string.push_str(key.as_ref());
+            string.push_str(kv_sep);
+            string.push_str(value.as_ref());
+        }
+
+        Uncased::from_owned(string)
+    }
+
+    /// Returns `self` as an `&UncasedStr`.
+    ///
+    /// This is equivalent to `&**self` but, unlike a deref coercion, is never
+    /// ambiguous, such as when passing `self` to a generic function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::{Uncased, UncasedStr};
+    ///
+    /// fn first_word<S: AsRef<str> + ?Sized>(s: &S) -> &str {
+    ///     s.as_ref().split('-').next().unwrap_or("")
+    /// }
+    ///
+    /// let uncased = Uncased::new("Content-Type");
+    /// let uncased_str: &UncasedStr = uncased.as_uncased_str();
+    /// assert_eq!(uncased_str, "content-type");
+    /// assert_eq!(first_word(uncased.as_uncased_str()), "Content");
     /// ```
     #[inline(always)]
     pub fn as_uncased_str(&self) -> &UncasedStr {
@@ -123,6 +221,42 @@ impl<'s> Uncased<'s> {
         }
     }
 
+    /// Converts `self` into its ASCII lowercase equivalent, allocating only if
+    /// `self` is borrowed. If `self` is owned, its allocation is reused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let lower = Uncased::new("Content-Type").into_ascii_lowercase();
+    /// assert_eq!(lower.as_str(), "content-type");
+    /// ```
+    #[inline]
+    pub fn into_ascii_lowercase(self) -> Uncased<'static> {
+        let mut string = self.into_string();
+        string.make_ascii_lowercase();
+        Uncased::from_owned(string)
+    }
+
+    /// Converts `self` into its ASCII uppercase equivalent, allocating only if
+    /// `self` is borrowed. If `self` is owned, its allocation is reused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let upper = Uncased::new("Content-Type").into_ascii_uppercase();
+    /// assert_eq!(upper.as_str(), "CONTENT-TYPE");
+    /// ```
+    #[inline]
+    pub fn into_ascii_uppercase(self) -> Uncased<'static> {
+        let mut string = self.into_string();
+        string.make_ascii_uppercase();
+        Uncased::from_owned(string)
+    }
+
     /// Converts `self` into a `Box<UncasedStr>`.
     ///
     /// # Example
@@ -144,6 +278,205 @@ impl<'s> Uncased<'s> {
         }
     }
 
+    /// Copies `self` into a new `Box<UncasedStr>` without consuming `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let uncased = Uncased::new("Content-Type");
+    /// let boxed = uncased.as_boxed_uncased();
+    /// assert_eq!(&*boxed, "content-type");
+    /// assert_eq!(boxed.as_str(), uncased.as_str());
+    /// ```
+    #[inline]
+    pub fn as_boxed_uncased(&self) -> Box<UncasedStr> {
+        Uncased::new(self.as_str()).into_boxed_uncased()
+    }
+
+    /// Appends `string` to the end of `self`, converting `self` into an owned
+    /// string if it is borrowed. Previously reserved capacity is used before
+    /// any reallocation occurs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let mut uncased = Uncased::new("Content");
+    /// uncased.push_str("-Type");
+    /// assert_eq!(uncased.as_str(), "Content-Type");
+    /// ```
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.string.to_mut().push_str(string);
+    }
+
+    /// Reserves capacity for at least `additional` more bytes, converting
+    /// `self` into an owned string if it is borrowed. See
+    /// [`String::reserve()`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let mut uncased = Uncased::new("Content");
+    /// uncased.reserve(10);
+    /// assert!(uncased.into_string().capacity() >= 17);
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.string.to_mut().reserve(additional);
+    }
+
+    /// Reserves capacity for exactly `additional` more bytes, converting
+    /// `self` into an owned string if it is borrowed. See
+    /// [`String::reserve_exact()`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let mut uncased = Uncased::new("Content");
+    /// uncased.reserve_exact(10);
+    /// assert!(uncased.into_string().capacity() >= 17);
+    /// ```
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.string.to_mut().reserve_exact(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more bytes,
+    /// converting `self` into an owned string if it is borrowed. Returns an
+    /// error if the capacity overflows or the allocator reports a failure. See
+    /// [`String::try_reserve()`] for details.
+    ///
+    /// This method is only available on Rust 1.57+.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let mut uncased = Uncased::new("Content");
+    /// uncased.try_reserve(10).expect("out of memory");
+    /// assert!(uncased.try_reserve(usize::MAX).is_err());
+    /// assert!(uncased.into_string().capacity() >= 17);
+    /// ```
+    #[inline]
+    #[cfg(try_reserve)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.string.to_mut().try_reserve(additional)
+    }
+
+    /// Appends the contents of `other` to the end of `self`, converting `self`
+    /// into an owned string if it is borrowed. The casing of both `self` and
+    /// `other` is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::{Uncased, UncasedStr};
+    ///
+    /// let mut uncased = Uncased::new("Foo-");
+    /// uncased.append(UncasedStr::new("Bar"));
+    /// assert_eq!(uncased.as_str(), "Foo-Bar");
+    /// assert_eq!(uncased, "foo-bar");
+    /// ```
+    #[inline]
+    pub fn append(&mut self, other: &UncasedStr) {
+        self.string.to_mut().push_str(other.as_str());
+    }
+
+    /// Removes the specified byte `range` from `self`, converting `self` into
+    /// an owned string if it is borrowed, and returns the removed characters
+    /// as an iterator. See [`String::drain()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start or end of `range` do not lie on a `char` boundary,
+    /// or if they are out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let mut uncased = Uncased::from_owned("Content-Type".to_string());
+    /// let drained: String = uncased.drain(7..8).collect();
+    /// assert_eq!(drained, "-");
+    /// assert_eq!(uncased.as_str(), "ContentType");
+    /// assert_eq!(uncased, "contenttype");
+    /// ```
+    #[inline]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> alloc::string::Drain<'_> {
+        self.string.to_mut().drain(range)
+    }
+
+    /// Removes and returns the `char` at byte index `idx`, converting `self`
+    /// into an owned string if it is borrowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than or equal to the length of `self`, or if
+    /// it does not lie on a `char` boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let mut uncased = Uncased::from_borrowed("Content-Type");
+    /// assert_eq!(uncased.remove(7), '-');
+    /// assert_eq!(uncased.remove(0), 'C');
+    /// assert_eq!(uncased.as_str(), "ontentType");
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> char {
+        self.string.to_mut().remove(idx)
+    }
+
+    /// Returns a mutable reference to the contents of `self`, converting
+    /// `self` into an owned string if it is borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let mut uncased = Uncased::new("Content-Type");
+    /// uncased.as_mut_str().make_ascii_uppercase();
+    /// assert_eq!(uncased.as_str(), "CONTENT-TYPE");
+    /// ```
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        self.string.to_mut().as_mut_str()
+    }
+
+    /// Returns a mutable reference to the inner `String`, converting `self`
+    /// into an owned string if it is borrowed.
+    ///
+    /// Any changes made through the returned reference, including changes in
+    /// case, are preserved by `self`, and comparisons continue to ignore case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::Uncased;
+    ///
+    /// let mut uncased = Uncased::from_borrowed("Hello");
+    /// uncased.as_mut_string().push('!');
+    /// assert_eq!(uncased.as_str(), "Hello!");
+    /// assert_eq!(uncased, "HELLO!");
+    /// ```
+    #[inline]
+    pub fn as_mut_string(&mut self) -> &mut String {
+        self.string.to_mut()
+    }
+
     /// Returns the inner `Cow`.
     #[doc(hidden)]
     #[inline(always)]
@@ -189,6 +522,13 @@ impl Borrow<UncasedStr> for Uncased<'_> {
     }
 }
 
+impl BorrowMut<UncasedStr> for Uncased<'_> {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut UncasedStr {
+        UncasedStr::new_mut(self.as_mut_str())
+    }
+}
+
 impl<'s, 'c: 's> From<&'c UncasedStr> for Uncased<'s> {
     #[inline(always)]
     fn from(string: &'c UncasedStr) -> Self {
@@ -217,6 +557,100 @@ impl<'s, 'c: 's> From<Cow<'c, str>> for Uncased<'s> {
     }
 }
 
+impl<'s> Extend<Uncased<'s>> for String {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Uncased<'s>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|uncased| self.push_str(uncased.as_str()));
+    }
+}
+
+impl<'s> core::iter::FromIterator<Uncased<'s>> for String {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Uncased<'s>>>(iter: I) -> Self {
+        let mut string = String::new();
+        string.extend(iter);
+        string
+    }
+}
+
+impl<'a> Extend<&'a UncasedStr> for Uncased<'_> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a UncasedStr>>(&mut self, iter: I) {
+        let string = self.string.to_mut();
+        iter.into_iter().for_each(|uncased| string.push_str(uncased.as_str()));
+    }
+}
+
+impl<'a> core::iter::FromIterator<&'a UncasedStr> for Uncased<'static> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a UncasedStr>>(iter: I) -> Self {
+        let mut uncased = Uncased::from_owned(String::new());
+        uncased.extend(iter);
+        uncased
+    }
+}
+
+impl core::iter::FromIterator<char> for Box<UncasedStr> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        // This is simply a `newtype`-like transformation. The
+        // `repr(transparent)` ensures that this is safe and correct.
+        let string: String = iter.into_iter().collect();
+        unsafe {
+            let raw_str = Box::into_raw(string.into_boxed_str());
+            Box::from_raw(raw_str as *mut UncasedStr)
+        }
+    }
+}
+
+impl<'s> core::iter::Sum<Uncased<'s>> for Uncased<'static> {
+    #[inline]
+    fn sum<I: Iterator<Item = Uncased<'s>>>(iter: I) -> Self {
+        Uncased::from(iter.collect::<String>())
+    }
+}
+
+impl<'a> From<&'a Uncased<'_>> for Cow<'a, str> {
+    #[inline(always)]
+    fn from(uncased: &'a Uncased<'_>) -> Self {
+        Cow::Borrowed(uncased.as_str())
+    }
+}
+
+impl<'s> From<Uncased<'s>> for Cow<'s, str> {
+    #[inline(always)]
+    fn from(uncased: Uncased<'s>) -> Self {
+        uncased.string
+    }
+}
+
+impl From<Uncased<'_>> for Box<str> {
+    #[inline]
+    fn from(uncased: Uncased<'_>) -> Self {
+        uncased.into_string().into_boxed_str()
+    }
+}
+
+impl From<Uncased<'_>> for Rc<UncasedStr> {
+    #[inline]
+    fn from(uncased: Uncased<'_>) -> Self {
+        // SAFETY: `UncasedStr` is repr(transparent)(str). As a result, `str`
+        // and `UncasedStr` have the same size and alignment. Furthermore, the
+        // pointer passed to `from_raw()` is clearly obtained by calling
+        // `into_raw()`. This fulfills the safety requirements of `from_raw()`.
+        let rc: Rc<str> = Rc::from(uncased.as_str());
+        let raw = Rc::into_raw(rc) as *const UncasedStr;
+        unsafe { Rc::from_raw(raw) }
+    }
+}
+
+impl From<Uncased<'_>> for Arc<UncasedStr> {
+    #[inline]
+    fn from(uncased: Uncased<'_>) -> Self {
+        Arc::from(uncased.as_uncased_str())
+    }
+}
+
 impl fmt::Display for Uncased<'_> {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -224,6 +658,12 @@ impl fmt::Display for Uncased<'_> {
     }
 }
 
+impl fmt::Debug for Uncased<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::borrowed::debug_fmt("Uncased", self.as_str(), f)
+    }
+}
+
 macro_rules! impl_partial_eq {
     ($other:ty $([$o_i:ident])? = $this:ty $([$t_i:ident])?) => (
         impl PartialEq<$other> for $this {
@@ -246,6 +686,10 @@ impl_partial_eq!(String [as_uncased] = Uncased<'_> [as_uncased_str]);
 impl_partial_eq!(Uncased<'_> [as_uncased_str] = String [as_uncased]);
 impl_partial_eq!(String [as_uncased] = &Uncased<'_> [as_uncased_str]);
 impl_partial_eq!(&Uncased<'_> [as_uncased_str] = String [as_uncased]);
+impl_partial_eq!(&String [as_uncased] = Uncased<'_> [as_uncased_str]);
+impl_partial_eq!(Uncased<'_> [as_uncased_str] = &String [as_uncased]);
+impl_partial_eq!(Cow<'_, str> [as_uncased] = Uncased<'_> [as_uncased_str]);
+impl_partial_eq!(Uncased<'_> [as_uncased_str] = Cow<'_, str> [as_uncased]);
 impl_partial_eq!(UncasedStr = Uncased<'_> [as_uncased_str]);
 impl_partial_eq!(&UncasedStr = Uncased<'_> [as_uncased_str]);
 impl_partial_eq!(Uncased<'_> [as_uncased_str] = UncasedStr);
@@ -275,6 +719,8 @@ impl Ord for Uncased<'_> {
 impl_partial_ord!(Uncased<'_> [as_uncased_str] >< Uncased<'_> [as_uncased_str]);
 impl_partial_ord!(str [as_uncased] >< Uncased<'_> [as_uncased_str]);
 impl_partial_ord!(Uncased<'_> [as_uncased_str] >< str [as_uncased]);
+impl_partial_ord!(&str [as_uncased] >< Uncased<'_> [as_uncased_str]);
+impl_partial_ord!(Uncased<'_> [as_uncased_str] >< &str [as_uncased]);
 impl_partial_ord!(String [as_uncased] >< Uncased<'_> [as_uncased_str]);
 impl_partial_ord!(Uncased<'_> [as_uncased_str] >< String [as_uncased]);
 impl_partial_ord!(String [as_uncased] >< &Uncased<'_> [as_uncased_str]);
@@ -11,7 +11,7 @@ impl<'a, 'de: 'a> Deserialize<'de> for &'a UncasedStr {
     }
 }
 
-impl<'a> Serialize for &'a UncasedStr {
+impl Serialize for &UncasedStr {
     fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.serialize_str(self.as_str())
     }
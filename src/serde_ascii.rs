@@ -0,0 +1,57 @@
+//! (De)serialization of uncased strings that must be pure ASCII.
+//!
+//! Because uncased strings only fold ASCII characters, comparisons between
+//! strings containing non-ASCII characters may be unexpectedly case-sensitive.
+//! This module can be used with serde's `with` attribute to reject such
+//! strings during deserialization. Values are serialized as usual, preserving
+//! case.
+//!
+//! # Example
+//!
+//! ```rust
+//! use serde_derive::{Serialize, Deserialize};
+//! use serde_test::{assert_tokens, assert_de_tokens_error, Token};
+//! use uncased::UncasedStr;
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config<'a> {
+//!     #[serde(borrow, with = "uncased::serde_ascii")]
+//!     key: &'a UncasedStr,
+//! }
+//!
+//! assert_tokens(&Config { key: "Key".into() }, &[
+//!     Token::Struct { name: "Config", len: 1 },
+//!     Token::Str("key"),
+//!     Token::BorrowedStr("Key"),
+//!     Token::StructEnd,
+//! ]);
+//!
+//! assert_de_tokens_error::<Config>(&[
+//!     Token::Struct { name: "Config", len: 1 },
+//!     Token::Str("key"),
+//!     Token::BorrowedStr("Kéy"),
+//! ], "invalid value: string \"Kéy\", expected an ASCII string");
+//! ```
+
+use serde::{Serializer, Deserialize, Deserializer};
+use serde::de::{Error, Unexpected};
+
+/// Serializes `value`, preserving case.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where T: AsRef<str> + ?Sized, S: Serializer
+{
+    serializer.serialize_str(value.as_ref())
+}
+
+/// Deserializes a `T`, returning an error if it contains non-ASCII characters.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where T: Deserialize<'de> + AsRef<str>, D: Deserializer<'de>
+{
+    let value = T::deserialize(deserializer)?;
+    if !value.as_ref().is_ascii() {
+        let unexpected = Unexpected::Str(value.as_ref());
+        return Err(D::Error::invalid_value(unexpected, &"an ASCII string"));
+    }
+
+    Ok(value)
+}
@@ -0,0 +1,60 @@
+//! (De)serialization of uncased strings in canonical, lowercase form.
+//!
+//! By default, uncased strings are serialized exactly as they were written,
+//! preserving case. This module can instead be used with serde's `with`
+//! attribute to serialize the ASCII lowercase form of the string. Values are
+//! deserialized as usual, so comparisons remain case-insensitive.
+//!
+//! # Example
+//!
+//! ```rust
+//! use serde_derive::{Serialize, Deserialize};
+//! use serde_test::{assert_tokens, Token};
+//! use uncased::UncasedStr;
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Header<'a> {
+//!     #[serde(borrow, with = "uncased::serde_canonical")]
+//!     name: &'a UncasedStr,
+//!     value: &'a str,
+//! }
+//!
+//! let header = Header { name: "Content-Type".into(), value: "text/html" };
+//! assert_tokens(&header, &[
+//!     Token::Struct { name: "Header", len: 2 },
+//!     Token::Str("name"),
+//!     Token::BorrowedStr("content-type"),
+//!     Token::Str("value"),
+//!     Token::BorrowedStr("text/html"),
+//!     Token::StructEnd,
+//! ]);
+//! ```
+
+use core::fmt;
+
+use serde::{Serializer, Deserialize, Deserializer};
+
+/// Displays a string in its ASCII lowercase form without allocating.
+struct Lowercase<'a>(&'a str);
+
+impl fmt::Display for Lowercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        self.0.chars().try_for_each(|c| f.write_char(c.to_ascii_lowercase()))
+    }
+}
+
+/// Serializes `value` as its ASCII lowercase form.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where T: AsRef<str> + ?Sized, S: Serializer
+{
+    serializer.collect_str(&Lowercase(value.as_ref()))
+}
+
+/// Deserializes a `T`, preserving the case of the input.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where T: Deserialize<'de>, D: Deserializer<'de>
+{
+    T::deserialize(deserializer)
+}
@@ -0,0 +1,196 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::fmt;
+
+use smol_str::SmolStr;
+
+use crate::{UncasedStr, AsUncased};
+
+/// An uncased (case-insensitive, case-preserving) ASCII string backed by a
+/// [`SmolStr`], which stores short strings inline without allocating.
+///
+/// # Example
+///
+/// ```rust
+/// use uncased::UncasedSmol;
+///
+/// let smol = UncasedSmol::new("Content-Type");
+/// assert_eq!(smol, "content-type");
+/// assert_eq!(smol.as_str(), "Content-Type");
+/// assert!(!smol.as_smol_str().is_heap_allocated());
+/// ```
+#[cfg_attr(nightly, doc(cfg(feature = "smol_str")))]
+#[derive(Clone)]
+pub struct UncasedSmol(SmolStr);
+
+impl UncasedSmol {
+    /// Creates a new `UncasedSmol` string from `string`, storing it inline if
+    /// it is short enough.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedSmol;
+    ///
+    /// let smol = UncasedSmol::new("Host");
+    /// assert_eq!(smol, "HOST");
+    /// ```
+    #[inline]
+    pub fn new<S: AsRef<str>>(string: S) -> UncasedSmol {
+        UncasedSmol(SmolStr::new(string))
+    }
+
+    /// Returns `self` as an `&UncasedStr`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedSmol;
+    ///
+    /// let smol = UncasedSmol::new("Host");
+    /// assert_eq!(smol.as_uncased_str(), "host");
+    /// ```
+    #[inline(always)]
+    pub fn as_uncased_str(&self) -> &UncasedStr {
+        UncasedStr::new(self.0.as_str())
+    }
+
+    /// Returns a reference to the inner `SmolStr`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedSmol;
+    ///
+    /// let smol = UncasedSmol::new("Host");
+    /// assert_eq!(smol.as_smol_str(), "Host");
+    /// ```
+    #[inline(always)]
+    pub fn as_smol_str(&self) -> &SmolStr {
+        &self.0
+    }
+
+    /// Converts `self` into the inner `SmolStr`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use uncased::UncasedSmol;
+    ///
+    /// let smol = UncasedSmol::new("Host");
+    /// assert_eq!(smol.into_smol_str(), "Host");
+    /// ```
+    #[inline(always)]
+    pub fn into_smol_str(self) -> SmolStr {
+        self.0
+    }
+}
+
+impl Deref for UncasedSmol {
+    type Target = UncasedStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &UncasedStr {
+        self.as_uncased_str()
+    }
+}
+
+impl AsRef<UncasedStr> for UncasedSmol {
+    #[inline(always)]
+    fn as_ref(&self) -> &UncasedStr {
+        self.as_uncased_str()
+    }
+}
+
+impl AsRef<str> for UncasedSmol {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Borrow<UncasedStr> for UncasedSmol {
+    #[inline(always)]
+    fn borrow(&self) -> &UncasedStr {
+        self.as_uncased_str()
+    }
+}
+
+impl From<&str> for UncasedSmol {
+    #[inline(always)]
+    fn from(string: &str) -> Self {
+        UncasedSmol::new(string)
+    }
+}
+
+impl From<&UncasedStr> for UncasedSmol {
+    #[inline(always)]
+    fn from(string: &UncasedStr) -> Self {
+        UncasedSmol::new(string.as_str())
+    }
+}
+
+impl From<SmolStr> for UncasedSmol {
+    #[inline(always)]
+    fn from(string: SmolStr) -> Self {
+        UncasedSmol(string)
+    }
+}
+
+impl fmt::Display for UncasedSmol {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for UncasedSmol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::borrowed::debug_fmt("UncasedSmol", self.as_str(), f)
+    }
+}
+
+macro_rules! impl_partial_eq {
+    ($other:ty $([$o_i:ident])? = $this:ty $([$t_i:ident])?) => (
+        impl PartialEq<$other> for $this {
+            #[inline(always)]
+            fn eq(&self, other: &$other) -> bool {
+                let this: &UncasedStr = self$(.$t_i())?;
+                let other: &UncasedStr = other$(.$o_i())?;
+                this.eq(other)
+            }
+        }
+    )
+}
+
+impl_partial_eq!(UncasedSmol [as_uncased_str] = UncasedSmol [as_uncased_str]);
+impl_partial_eq!(str [as_uncased] = UncasedSmol [as_uncased_str]);
+impl_partial_eq!(UncasedSmol [as_uncased_str] = str [as_uncased]);
+impl_partial_eq!(&str [as_uncased] = UncasedSmol [as_uncased_str]);
+impl_partial_eq!(UncasedSmol [as_uncased_str] = &str [as_uncased]);
+impl_partial_eq!(UncasedStr = UncasedSmol [as_uncased_str]);
+impl_partial_eq!(UncasedSmol [as_uncased_str] = UncasedStr);
+
+impl Eq for UncasedSmol { }
+
+impl PartialOrd for UncasedSmol {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &UncasedSmol) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UncasedSmol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_uncased_str().cmp(other.as_uncased_str())
+    }
+}
+
+impl Hash for UncasedSmol {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_uncased_str().hash(hasher)
+    }
+}
@@ -1,10 +1,10 @@
 #![allow(deprecated)]
 
-use crate::UncasedStr;
+use crate::{UncasedStr, UncasedBytes};
 
 use core::hash::{Hash, Hasher, SipHasher};
 
-fn hash<T: Hash>(t: &T) -> u64 {
+fn hash<T: Hash + ?Sized>(t: &T) -> u64 {
     let mut s = SipHasher::new();
     t.hash(&mut s);
     s.finish()
@@ -59,8 +59,805 @@ fn test_case_cmp() {
     assert!(UncasedStr::new("aA") > UncasedStr::new("A"));
 }
 
+#[test]
+fn test_bytes_cmp() {
+    assert!(UncasedBytes::new(b"foobar") == UncasedBytes::new(b"FOOBAR"));
+    assert!(UncasedBytes::new(b"\xFFa") == UncasedBytes::new(b"\xFFA"));
+    assert!(UncasedBytes::new(b"\xFFa") != UncasedBytes::new(b"\xFEA"));
+
+    assert!(UncasedBytes::new(b"a") < UncasedBytes::new(b"B"));
+    assert!(UncasedBytes::new(b"A") < UncasedBytes::new(b"b"));
+    assert!(UncasedBytes::new(b"aA") > UncasedBytes::new(b"A"));
+    assert!(UncasedBytes::new(b"_") < UncasedBytes::new(b"A"));
+
+    let strings = ["Content-Type", "CONTENT-TYPE", "content-type", "cOnTeNt-TyPe"];
+    for (a, b) in strings.iter().zip(strings.iter().rev()) {
+        let (bytes_a, bytes_b) = (UncasedBytes::new(a.as_bytes()), UncasedBytes::new(b.as_bytes()));
+        assert_eq!(bytes_a, bytes_b);
+        assert_eq!(hash(bytes_a), hash(bytes_b));
+        assert_eq!(hash(bytes_a), hash(UncasedStr::new(a)));
+        assert_eq!(bytes_a, UncasedStr::new(b).as_uncased_bytes());
+    }
+
+    let bytes = UncasedBytes::new(b"Content-Type: \xFF");
+    assert!(bytes.starts_with(b"content-TYPE"));
+    assert!(bytes.contains(b"TYPE: \xFF"));
+    assert!(!bytes.contains(b"type: \xFE"));
+
+    let debug = alloc::format!("{:?}", UncasedBytes::new(b"A\"\xFF\n"));
+    assert_eq!(debug, r#"UncasedBytes(b"A\"\xff\n" /* = b"a\"\xff\n" */)"#);
+}
+
 #[test]
 fn test_into_arc() {
     let arced: alloc::sync::Arc<UncasedStr> = UncasedStr::new("FOOBAR").into();
     assert!(UncasedStr::new("foobar") == arced.as_ref());
 }
+
+#[test]
+fn test_uncased_into_rc_arc() {
+    use alloc::{boxed::Box, rc::Rc, sync::Arc};
+    use crate::Uncased;
+
+    let rc: Rc<UncasedStr> = Uncased::new("Content-Type").into();
+    let rc_clone = rc.clone();
+    assert!(Rc::ptr_eq(&rc, &rc_clone));
+    assert_eq!(&*rc_clone, "content-type");
+    assert_eq!(rc_clone.as_str(), "Content-Type");
+
+    let arc: Arc<UncasedStr> = Uncased::from_owned("Content-Type".into()).into();
+    let arc_clone = arc.clone();
+    assert!(Arc::ptr_eq(&arc, &arc_clone));
+    assert_eq!(&*arc_clone, "CONTENT-TYPE");
+    assert_eq!(*arc_clone, *rc);
+
+    let boxed: Box<str> = Uncased::new("Host").into();
+    assert_eq!(&*boxed, "Host");
+}
+
+#[test]
+fn test_string_from_uncased_iter() {
+    use crate::Uncased;
+    use alloc::string::String;
+
+    let parts = [Uncased::new("Content"), Uncased::new("-"), Uncased::new("TYPE")];
+    let string: String = parts.iter().cloned().collect();
+    assert_eq!(string, "Content-TYPE");
+
+    let mut string = String::from("X-");
+    string.extend(parts.iter().cloned());
+    assert_eq!(string, "X-Content-TYPE");
+}
+
+#[test]
+fn test_cursor() {
+    use crate::UncasedCursor;
+
+    let mut cursor = UncasedCursor::new(UncasedStr::new("Bearer abc"));
+    assert!(cursor.eat("bearer "));
+    assert_eq!(cursor.rest().as_str(), "abc");
+    assert_eq!(cursor.rest(), "ABC");
+
+    let mut cursor = UncasedCursor::new(UncasedStr::new("  Bearer"));
+    assert_eq!(cursor.eat_while(|c| c == ' ').as_str(), "  ");
+    assert_eq!(cursor.eat_while(|c| c == ' ').as_str(), "");
+    assert!(!cursor.eat("bearers"));
+    assert!(cursor.eat("BEARER"));
+    assert!(cursor.rest().is_empty());
+}
+
+#[test]
+fn test_cmp_slices() {
+    use core::cmp::Ordering;
+
+    assert_eq!(crate::cmp_slices(&["A", "b"], &["a", "C"]), Ordering::Less);
+    assert_eq!(crate::cmp_slices(&["a", "C"], &["A", "b"]), Ordering::Greater);
+    assert_eq!(crate::cmp_slices(&["A", "b"], &["a", "B"]), Ordering::Equal);
+    assert_eq!(crate::cmp_slices(&["a"], &["A", "b"]), Ordering::Less);
+    assert_eq!(crate::cmp_slices::<&str, &str>(&[], &[]), Ordering::Equal);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serde_canonical() {
+    use serde::de::value::{BorrowedStrDeserializer, Error};
+    use serde_test::{assert_ser_tokens, Token};
+    use crate::serde_canonical;
+
+    #[derive(serde_derive::Serialize)]
+    #[serde(transparent, bound = "T: AsRef<str>")]
+    struct Canonical<T>(#[serde(with = "serde_canonical")] T);
+
+    let uncased = UncasedStr::new("Content-Type");
+    assert_ser_tokens(&uncased, &[Token::Str("Content-Type")]);
+    assert_ser_tokens(&Canonical(uncased), &[Token::Str("content-type")]);
+    assert_ser_tokens(&Canonical("Bèe"), &[Token::Str("bèe")]);
+
+    #[cfg(feature = "with-serde-alloc")] {
+        let owned = crate::Uncased::new("X-Custom");
+        assert_ser_tokens(&owned, &[Token::Str("X-Custom")]);
+        assert_ser_tokens(&Canonical(owned), &[Token::Str("x-custom")]);
+    }
+
+    let de = BorrowedStrDeserializer::<Error>::new("Content-Type");
+    let uncased: &UncasedStr = serde_canonical::deserialize(de).unwrap();
+    assert_eq!(uncased.as_str(), "Content-Type");
+    assert_eq!(uncased, "content-type");
+}
+
+#[test]
+fn test_to_ascii_lowercase_cow() {
+    use alloc::borrow::Cow;
+
+    let lower = UncasedStr::new("already-lower 123").to_ascii_lowercase_cow();
+    assert!(matches!(lower, Cow::Borrowed("already-lower 123")));
+
+    let lower = UncasedStr::new("Bèe-HIVE").to_ascii_lowercase_cow();
+    assert!(matches!(lower, Cow::Owned(_)));
+    assert_eq!(lower, "bèe-hive");
+}
+
+#[test]
+fn test_as_boxed_uncased() {
+    let borrowed = crate::Uncased::from_borrowed("Content-Type");
+    let boxed = borrowed.as_boxed_uncased();
+    assert_eq!(&*boxed, borrowed.as_uncased_str());
+    assert_eq!(boxed.as_str(), "Content-Type");
+    assert_ne!(boxed.as_str().as_ptr(), borrowed.as_str().as_ptr());
+}
+
+#[test]
+#[cfg(feature = "subtle")]
+fn test_ct_eq() {
+    let strings = ["", "a", "A", "b", "@", "`", "[", "{", "Content-Type",
+        "content-type", "CONTENT-TYPO", "Bèe", "bèE", "bÈe"];
+
+    for a in strings.iter().map(|s| UncasedStr::new(s)) {
+        for b in strings.iter().map(|s| UncasedStr::new(s)) {
+            assert_eq!(a.ct_eq(b), a == b, "{:?} vs {:?}", a, b);
+        }
+    }
+
+    assert!(!UncasedStr::new("token").ct_eq("tokens".into()));
+    assert!(!UncasedStr::new("tokens").ct_eq("token".into()));
+}
+
+#[test]
+fn test_debug() {
+    let debug = alloc::format!("{:?}", UncasedStr::new("Content-Type"));
+    assert_eq!(debug, r#"UncasedStr("Content-Type" /* = "content-type" */)"#);
+
+    let debug = alloc::format!("{:?}", crate::Uncased::new("X-\"Quoted\""));
+    assert_eq!(debug, r#"Uncased("X-\"Quoted\"" /* = "x-\"quoted\"" */)"#);
+
+    let debug = alloc::format!("{:?}", crate::Uncased::new("a\nB\u{1b}[31m x'"));
+    assert_eq!(debug, r#"Uncased("a\nB\u{1b}[31m x'" /* = "a\nb\u{1b}[31m x'" */)"#);
+    assert!(!debug.contains('\n') && !debug.contains('\u{1b}'));
+
+    let tricky = "\u{301}E\u{301}\u{200b}";
+    let debug = alloc::format!("{:?}", UncasedStr::new(tricky));
+    let folded = alloc::format!("{:?}", tricky.to_ascii_lowercase());
+    assert!(debug.ends_with(&alloc::format!("/* = {} */)", folded)));
+}
+
+#[test]
+fn test_into_cow() {
+    use alloc::borrow::Cow;
+    use crate::Uncased;
+
+    fn cow<'a, S: Into<Cow<'a, str>>>(string: S) -> Cow<'a, str> {
+        string.into()
+    }
+
+    let owned = Uncased::from_owned("Content-Type".into());
+    assert!(matches!(cow(&owned), Cow::Borrowed("Content-Type")));
+    assert!(matches!(cow(owned), Cow::Owned(s) if s == "Content-Type"));
+
+    let borrowed: Uncased<'static> = Uncased::from_borrowed("Host");
+    assert!(matches!(cow(borrowed), Cow::Borrowed("Host")));
+}
+
+#[test]
+fn test_eq_by() {
+    let dash = |c| if c == '_' { '-' } else { c };
+    assert!(UncasedStr::new("X-Y").eq_by("x_y".into(), dash));
+    assert!(UncasedStr::new("").eq_by("".into(), dash));
+    assert!(!UncasedStr::new("X-Y").eq_by("x_".into(), dash));
+    assert!(!UncasedStr::new("X-").eq_by("x_y".into(), dash));
+    assert!(!UncasedStr::new("X-Y").eq_by("x_y".into(), |c| c));
+}
+
+#[test]
+fn test_const_empty() {
+    use crate::Uncased;
+
+    static UNCASED: Uncased = Uncased::empty();
+    const CONST: Uncased = Uncased::empty();
+
+    assert_eq!(UNCASED, CONST);
+    assert_eq!(UNCASED.as_str(), "");
+    assert!(matches!(CONST.into_cow(), alloc::borrow::Cow::Borrowed("")));
+}
+
+#[test]
+fn test_char_windows() {
+    let windows: alloc::vec::Vec<_> = UncasedStr::new("ABC").char_windows(2).collect();
+    assert_eq!(windows, ["ab", "BC"]);
+
+    let windows: alloc::vec::Vec<_> = UncasedStr::new("aBc").char_windows(3).collect();
+    assert_eq!(windows, ["ABC"]);
+
+    assert_eq!(UncasedStr::new("ABC").char_windows(4).count(), 0);
+    assert_eq!(UncasedStr::new("").char_windows(1).count(), 0);
+    assert_eq!(UncasedStr::new("èèè").char_windows(1).count(), 3);
+}
+
+#[test]
+#[cfg(feature = "fuzzy")]
+fn test_levenshtein() {
+    let distance = |a: &str, b: &str| UncasedStr::new(a).levenshtein(b.into());
+
+    assert_eq!(distance("Kitten", "SITTING"), 3);
+    assert_eq!(distance("SITTING", "Kitten"), 3);
+    assert_eq!(distance("", ""), 0);
+    assert_eq!(distance("", "abc"), 3);
+    assert_eq!(distance("Flaw", "lawn"), 2);
+    assert_eq!(distance("Content-Type", "content-type"), 0);
+    assert_eq!(distance("bèe", "BÈE"), 1);
+}
+
+#[test]
+fn test_sum() {
+    use crate::Uncased;
+
+    let fragments = [Uncased::new("Content"), Uncased::new("-"), Uncased::new("TYPE")];
+    let sum: Uncased<'static> = fragments.iter().cloned().sum();
+    assert_eq!(sum.as_str(), "Content-TYPE");
+    assert_eq!(sum, "content-type");
+
+    let empty: Uncased<'static> = core::iter::empty::<Uncased>().sum();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_eq_bytes() {
+    let get = UncasedStr::new("GET");
+    assert!(get == b"get"[..]);
+    assert!(*get == b"GET"[..]);
+    assert!(get != b"post"[..]);
+
+    let (lower, short): (&[u8], &[u8]) = (b"get", b"ge");
+    assert!(*get == lower);
+    assert!(*get != short);
+    assert!(*UncasedStr::new("Bèe") == "BèE".as_bytes());
+}
+
+#[test]
+fn test_rsplit() {
+    use alloc::vec::Vec;
+
+    let parts: Vec<_> = UncasedStr::new("a.b.c").rsplit(".").collect();
+    assert_eq!(parts, ["c", "b", "a"]);
+
+    let parts: Vec<_> = UncasedStr::new(".aXXbxX").rsplit("xx").collect();
+    assert_eq!(parts, ["", "B", ".A"]);
+    assert_eq!(parts[1].as_str(), "b");
+
+    let parts: Vec<_> = UncasedStr::new("èaèbè").rsplit("è").collect();
+    assert_eq!(parts, ["", "b", "a", ""]);
+
+    let parts: Vec<_> = UncasedStr::new("abc").rsplit("").collect();
+    assert_eq!(parts, ["abc"]);
+
+    let parts: Vec<_> = UncasedStr::new("").rsplit(".").collect();
+    assert_eq!(parts, [""]);
+}
+
+#[test]
+fn test_collect_str_capacity() {
+    let chars = "AbC".chars().cycle().take(10_000);
+    let uncased = crate::Uncased::collect_str(chars);
+    assert_eq!(uncased.len(), 10_000);
+    assert!(uncased.starts_with("abcabc"));
+
+    let string = uncased.into_string();
+    assert_eq!(string.capacity(), 10_000);
+}
+
+#[test]
+fn test_split_keep_sep() {
+    use alloc::vec::Vec;
+
+    let parts: Vec<_> = UncasedStr::new("a;b;c").split_keep_sep(";").collect();
+    assert_eq!(parts, ["a;", "b;", "c"]);
+
+    let parts: Vec<_> = UncasedStr::new("xAbyaBz").split_keep_sep("ab").collect();
+    let parts: Vec<_> = parts.iter().map(|s| s.as_str()).collect();
+    assert_eq!(parts, ["xAb", "yaB", "z"]);
+
+    let parts: Vec<_> = UncasedStr::new(";;").split_keep_sep(";").collect();
+    assert_eq!(parts, [";", ";", ""]);
+
+    let parts: Vec<_> = UncasedStr::new("abc").split_keep_sep("").collect();
+    assert_eq!(parts, ["abc"]);
+}
+
+#[test]
+fn test_borrow_mut() {
+    use core::borrow::BorrowMut;
+
+    let original = "Content-Type";
+    let mut uncased = crate::Uncased::from_borrowed(original);
+    {
+        let uncased_str: &mut UncasedStr = uncased.borrow_mut();
+        uncased_str.make_ascii_lowercase();
+        assert_eq!(uncased_str.as_str(), "content-type");
+    }
+
+    assert_eq!(uncased.as_str(), "content-type");
+    assert_eq!(original, "Content-Type");
+
+    uncased.as_mut_str().make_ascii_uppercase();
+    assert_eq!(uncased.as_str(), "CONTENT-TYPE");
+}
+
+#[test]
+fn test_hash_consistency() {
+    use alloc::sync::Arc;
+    use crate::Uncased;
+
+    for string in ["", "a", "Content-Type", "CONTENT-TYPE", "Bèe", "ßtraße"] {
+        let uncased_str = UncasedStr::new(string);
+        let expected = hash(&uncased_str);
+
+        assert_eq!(hash(uncased_str), expected);
+        assert_eq!(hash(&Uncased::new(string)), expected);
+        assert_eq!(hash(&Uncased::from_owned(string.into())), expected);
+        assert_eq!(hash(&Uncased::new(string).into_boxed_uncased()), expected);
+        assert_eq!(hash(&&Uncased::new(string)), expected);
+        assert_eq!(hash(&Arc::<UncasedStr>::from(uncased_str)), expected);
+        assert_eq!(hash(&UncasedStr::new(&string.to_ascii_uppercase())), expected);
+    }
+}
+
+#[test]
+fn test_trim_ascii() {
+    let uncased_str = UncasedStr::new(" \t\x0CContent-Type\r\n ");
+    assert_eq!(uncased_str.trim_ascii().as_str(), "Content-Type");
+    assert_eq!(uncased_str.trim_ascii_start().as_str(), "Content-Type\r\n ");
+    assert_eq!(uncased_str.trim_ascii_end().as_str(), " \t\x0CContent-Type");
+    assert_eq!(uncased_str.trim_ascii(), "content-type");
+
+    assert_eq!(UncasedStr::new("").trim_ascii().as_str(), "");
+    assert_eq!(UncasedStr::new(" \t ").trim_ascii().as_str(), "");
+    assert_eq!(UncasedStr::new("\u{A0}è\u{A0}").trim_ascii().as_str(), "\u{A0}è\u{A0}");
+}
+
+#[test]
+#[cfg(const_fn_transmute)]
+fn test_const_trim_ascii() {
+    const TRIMMED: &UncasedStr = UncasedStr::new("  Host \n").trim_ascii();
+    assert_eq!(TRIMMED.as_str(), "Host");
+    assert_eq!(TRIMMED, "HOST");
+}
+
+#[test]
+#[cfg(feature = "fxhash")]
+fn test_fxhash() {
+    let fxhash = |s: &str| UncasedStr::new(s).fxhash();
+
+    assert_eq!(fxhash("Abc"), fxhash("ABC"));
+    assert_eq!(fxhash("content-type-header"), fxhash("Content-Type-HEADER"));
+    assert_ne!(fxhash("content-type-header"), fxhash("content-type-headers"));
+    assert_ne!(fxhash("a"), fxhash("b"));
+    assert_eq!(fxhash(""), 0);
+
+    // Known answers, matching `rustc_hash::FxHasher` 1.x on the lowercase form.
+    assert_eq!(fxhash("a"), 0xe0456665d3e60275);
+    assert_eq!(fxhash("AB"), 0xa26d8360dbf30c75);
+    assert_eq!(fxhash("abc"), 0xc360d75917ea8923);
+    assert_eq!(fxhash("abcd"), 0x178af1ad27920c75);
+    assert_eq!(fxhash("abcdefg"), 0xfb55d6a1677ebff8);
+    assert_eq!(fxhash("Content-Type"), 0x155eb7b67d701128);
+    assert_eq!(fxhash("ABCDEFGHIJKLMNOPQRSTUVW"), 0xb5dbad8fcf4e5eb2);
+}
+
+#[test]
+fn test_owned_eq_uncased() {
+    use alloc::{borrow::Cow, string::String};
+    use crate::Uncased;
+
+    let (yes, no) = (String::from("YES"), String::from("NO"));
+    let (yes_ref, no_ref): (&String, &String) = (&yes, &no);
+
+    assert!(yes == Uncased::new("yes"));
+    assert!(yes_ref == Uncased::new("yes"));
+    assert!(Cow::Borrowed("YES") == Uncased::new("yes"));
+    assert!(Cow::<str>::Owned(yes.clone()) == Uncased::new("yes"));
+    assert!(no != Uncased::new("yes"));
+    assert!(no_ref != Uncased::new("yes"));
+
+    assert!(Uncased::new("yes") == yes);
+    assert!(Uncased::new("yes") == yes_ref);
+    assert!(Uncased::new("yes") == Cow::Borrowed("YES"));
+    assert!(Uncased::new("yes") != Cow::Borrowed("NO"));
+}
+
+#[test]
+fn test_escape_debug() {
+    let value = UncasedStr::new("A\tB\nc\u{0}è");
+    assert_eq!(value.escape_debug(), "A\\tB\\nc\\0è");
+    assert_eq!(UncasedStr::new("Plain").escape_debug(), "Plain");
+}
+
+#[test]
+fn test_reserve_exact() {
+    let mut uncased = crate::Uncased::from_borrowed("Content");
+    uncased.reserve_exact(20);
+    uncased.push_str("-Type");
+    uncased.push_str("-Header");
+    assert_eq!(uncased, "content-type-header");
+
+    let string = uncased.into_string();
+    assert_eq!(string.capacity(), "Content".len() + 20);
+}
+
+#[test]
+fn test_eq_string_ref() {
+    let (string, other) = (alloc::string::String::from("Content-Type"), "Host".into());
+    let (string_ref, other_ref): (&alloc::string::String, &alloc::string::String) = (&string, &other);
+
+    let uncased_str = UncasedStr::new("content-TYPE");
+    assert!(*uncased_str == string_ref);
+    assert!(string_ref == *uncased_str);
+    assert!(*uncased_str != other_ref);
+    assert!(other_ref != *uncased_str);
+}
+
+#[test]
+fn test_from_utf16() {
+    use crate::Uncased;
+
+    let utf16 = [0x0042, 0x00E8, 0x0065, 0xD834, 0xDD1E];
+    let uncased = Uncased::from_utf16(&utf16).unwrap();
+    assert_eq!(uncased.as_str(), "Bèe\u{1D11E}");
+    assert_eq!(uncased, "bèE\u{1D11E}");
+
+    assert!(Uncased::from_utf16(&[0xD834]).is_err());
+    assert!(Uncased::from_utf16(&[0xDD1E, 0xD834]).is_err());
+    assert_eq!(Uncased::from_utf16(&[]).unwrap(), "");
+}
+
+#[test]
+fn test_write_folded() {
+    for string in ["", "a", "A", "Content-Type", "CONTENT-TYPE", "Bèe", "@[`{"] {
+        let mut expected = SipHasher::new();
+        string.bytes().for_each(|b| expected.write_u8(b.to_ascii_lowercase()));
+        let expected = expected.finish();
+
+        let mut folded = SipHasher::new();
+        crate::write_folded(string, &mut folded);
+        assert_eq!(folded.finish(), expected);
+        assert_eq!(hash(UncasedStr::new(string)), expected);
+        assert_eq!(hash(UncasedBytes::new(string.as_bytes())), expected);
+    }
+}
+
+#[test]
+fn test_split_numeric_suffix() {
+    let split = |s| {
+        let (stem, digits) = UncasedStr::new(s).split_numeric_suffix();
+        (stem.as_str(), digits.map(|d| d.as_str()))
+    };
+
+    assert_eq!(split("ABC123"), ("ABC", Some("123")));
+    assert_eq!(split("Item42"), ("Item", Some("42")));
+    assert_eq!(split("a1b2"), ("a1b", Some("2")));
+    assert_eq!(split("plain"), ("plain", None));
+    assert_eq!(split("999"), ("", Some("999")));
+    assert_eq!(split("è٣"), ("è٣", None));
+    assert_eq!(split(""), ("", None));
+}
+
+#[test]
+fn test_try_into_char() {
+    use core::convert::TryFrom;
+
+    assert_eq!(char::try_from(UncasedStr::new("A")), Ok('A'));
+    assert_eq!(char::try_from(UncasedStr::new("è")), Ok('è'));
+    assert!(char::try_from(UncasedStr::new("")).is_err());
+    assert!(char::try_from(UncasedStr::new("ab")).is_err());
+    assert!(char::try_from(UncasedStr::new("èè")).is_err());
+
+    let empty = char::try_from(UncasedStr::new("")).unwrap_err();
+    let many = char::try_from(UncasedStr::new("abc")).unwrap_err();
+    assert_ne!(empty, many);
+    assert_eq!(alloc::format!("{}", empty), "cannot parse char from empty string");
+}
+
+#[test]
+fn test_glob_match() {
+    let glob = |s, p| UncasedStr::new(s).glob_match(p);
+
+    assert!(glob("Index.HTML", "index.*"));
+    assert!(glob("Index.HTML", "*"));
+    assert!(glob("Index.HTML", "**.h?ml"));
+    assert!(glob("Index.HTML", "I*X*H*L"));
+    assert!(glob("", ""));
+    assert!(glob("", "*"));
+    assert!(glob("aaab", "*a*b"));
+    assert!(glob("Bèe", "b?E"));
+    assert!(glob("/API/v1/users", "/api/*/USERS"));
+
+    assert!(!glob("Index.HTML", "index.*s"));
+    assert!(!glob("Index.HTML", "index"));
+    assert!(!glob("Index.HTML", "?index.html"));
+    assert!(!glob("", "?"));
+    assert!(!glob("abc", ""));
+    assert!(!glob("aaa", "*b*"));
+}
+
+#[test]
+fn test_as_uncased_str_generic() {
+    use core::borrow::Borrow;
+    use crate::Uncased;
+
+    fn contains<K: Borrow<UncasedStr>>(keys: &[K], key: &UncasedStr) -> bool {
+        keys.iter().any(|k| k.borrow() == key)
+    }
+
+    let uncased = Uncased::new("Content-Type");
+    let keys = [UncasedStr::new("Host"), UncasedStr::new("CONTENT-TYPE")];
+    assert!(contains(&keys, uncased.as_uncased_str()));
+    assert!(!contains(&keys, Uncased::new("Accept").as_uncased_str()));
+}
+
+#[test]
+fn test_parse_bool() {
+    for s in ["true", "True", "tRUE", "yes", "YeS", "on", "ON", "1"] {
+        assert_eq!(UncasedStr::new(s).parse_bool(), Some(true), "{}", s);
+    }
+
+    for s in ["false", "FALSE", "fAlSe", "no", "nO", "off", "OfF", "0"] {
+        assert_eq!(UncasedStr::new(s).parse_bool(), Some(false), "{}", s);
+    }
+
+    for s in ["", "t", "y", "2", "onn", " true", "yes!", "nope"] {
+        assert_eq!(UncasedStr::new(s).parse_bool(), None, "{}", s);
+    }
+}
+
+#[test]
+fn test_into_ascii_case() {
+    use crate::Uncased;
+
+    let borrowed = Uncased::from_borrowed("Bèe-Hive");
+    assert_eq!(borrowed.clone().into_ascii_lowercase().as_str(), "bèe-hive");
+    assert_eq!(borrowed.into_ascii_uppercase().as_str(), "BèE-HIVE");
+
+    let owned = Uncased::from_owned("Bèe-Hive".into());
+    let ptr = owned.as_str().as_ptr();
+    let lower = owned.into_ascii_lowercase();
+    assert_eq!(lower.as_str(), "bèe-hive");
+    assert_eq!(lower.as_str().as_ptr(), ptr);
+
+    let upper = lower.into_ascii_uppercase();
+    assert_eq!(upper.as_str(), "BèE-HIVE");
+    assert_eq!(upper.as_str().as_ptr(), ptr);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serde_ascii() {
+    use serde::de::value::{BorrowedStrDeserializer, Error};
+    use crate::serde_ascii;
+
+    let de = BorrowedStrDeserializer::<Error>::new("Content-Type");
+    let uncased: &UncasedStr = serde_ascii::deserialize(de).unwrap();
+    assert_eq!(uncased.as_str(), "Content-Type");
+
+    let de = BorrowedStrDeserializer::<Error>::new("Bèe");
+    assert!(serde_ascii::deserialize::<&UncasedStr, _>(de).is_err());
+}
+
+#[test]
+fn test_longest_prefix() {
+    let routes = ["/api", "/API/v1", "/", "/api/V1"];
+    let longest = |s| UncasedStr::new(s).longest_prefix(&routes).copied();
+
+    assert_eq!(longest("/api/v1/users"), Some("/API/v1"));
+    assert_eq!(longest("/Api/users"), Some("/api"));
+    assert_eq!(longest("/index.html"), Some("/"));
+    assert_eq!(longest("index.html"), None);
+    assert_eq!(UncasedStr::new("/").longest_prefix::<&str>(&[]), None);
+}
+
+#[test]
+fn test_uncased_cmp_str_ref() {
+    use crate::Uncased;
+
+    assert!(Uncased::new("m") < "Z");
+    assert!(Uncased::new("M") > "a");
+    assert!(Uncased::new("m") <= "M");
+    assert!("Z" > Uncased::new("m"));
+    assert!("a" < Uncased::new("M"));
+    assert!("M" >= Uncased::new("m"));
+}
+
+#[test]
+fn test_split_ascii_whitespace() {
+    use alloc::vec::Vec;
+
+    let string = "Content-Type:\u{A0}text/html \t charset=UTF-8\n";
+    let words: Vec<_> = UncasedStr::new(string).split_ascii_whitespace().collect();
+    assert_eq!(words, ["content-type:\u{A0}TEXT/HTML", "CHARSET=utf-8"]);
+    assert_eq!(words[1].as_str(), "charset=UTF-8");
+    assert_eq!(string.split_whitespace().count(), 3);
+
+    assert_eq!(UncasedStr::new(" \t\n").split_ascii_whitespace().count(), 0);
+}
+
+#[test]
+fn test_eq_ignoring() {
+    let separator = |c| c == '-' || c == ' ';
+    let eq = |a, b: &str| UncasedStr::new(a).eq_ignoring(b.into(), separator);
+
+    assert!(eq("AB-CD", "ab cd"));
+    assert!(eq("+1 555-0100", "+15550100"));
+    assert!(eq("", "- -"));
+    assert!(!eq("AB-CD", "abc"));
+    assert!(!eq("AB-CD", "ab-cde"));
+    assert!(UncasedStr::new("AB").eq_ignoring("ab".into(), |_| false));
+    assert!(UncasedStr::new("AB").eq_ignoring("xyz".into(), |_| true));
+}
+
+#[test]
+fn test_uncased_from_uncased_str_iter() {
+    use crate::Uncased;
+
+    let uncased: Uncased = [UncasedStr::new("A"), UncasedStr::new("b")].iter().copied().collect();
+    assert_eq!(uncased.as_str(), "Ab");
+    assert_eq!(uncased, "AB");
+
+    let mut uncased = Uncased::from_borrowed("Content");
+    uncased.extend(["-", "Type"].iter().map(|s| UncasedStr::new(s)));
+    assert_eq!(uncased.as_str(), "Content-Type");
+}
+
+#[test]
+fn test_rmatch_indices() {
+    use alloc::vec::Vec;
+
+    let indices = |s, p| -> Vec<_> {
+        UncasedStr::new(s).rmatch_indices(p).map(|(i, m)| (i, m.as_str())).collect()
+    };
+
+    assert_eq!(indices("aXaXa", "x"), [(3, "X"), (1, "X")]);
+    assert_eq!(indices("aXaXa", "A"), [(4, "a"), (2, "a"), (0, "a")]);
+    assert_eq!(indices("aaaa", "AA"), [(2, "aa"), (0, "aa")]);
+    assert_eq!(indices("aaa", "AA"), [(1, "aa")]);
+    assert_eq!(indices("èXè", "x"), [(2, "X")]);
+    assert_eq!(indices("abc", "d"), []);
+    assert_eq!(indices("abc", ""), []);
+    assert_eq!(UncasedStr::new("xXx").rmatches("x").count(), 3);
+}
+
+#[test]
+#[cfg(feature = "smol_str")]
+fn test_uncased_smol() {
+    use crate::UncasedSmol;
+
+    let short = UncasedSmol::new("Content-Type");
+    assert!(!short.as_smol_str().is_heap_allocated());
+    assert_eq!(short, "CONTENT-TYPE");
+    assert_eq!(short, UncasedSmol::from("content-type"));
+    assert_eq!(short.as_str(), "Content-Type");
+    assert_eq!(alloc::format!("{}", short), "Content-Type");
+    assert_eq!(hash(&short), hash(UncasedStr::new("content-type")));
+
+    let long = UncasedSmol::new("X-A-Very-Long-Header-Name-That-Spills");
+    assert!(long.as_smol_str().is_heap_allocated());
+    assert_eq!(long, "x-a-very-long-header-name-that-spills");
+
+    assert!(UncasedSmol::new("a") < UncasedSmol::new("B"));
+    assert!(UncasedSmol::new("Host").starts_with("ho"));
+}
+
+#[test]
+fn test_trim_matches_char() {
+    let s = UncasedStr::new("AaXyz");
+    assert_eq!(s.trim_start_matches_char('a').as_str(), "Xyz");
+    assert_eq!(s.trim_start_matches_char('A').as_str(), "Xyz");
+    assert_eq!(s.trim_end_matches_char('Z').as_str(), "AaXy");
+
+    let all = UncasedStr::new("aAaA");
+    assert!(all.trim_start_matches_char('a').is_empty());
+    assert!(all.trim_end_matches_char('A').is_empty());
+    assert!(UncasedStr::new("").trim_start_matches_char('a').is_empty());
+}
+
+#[test]
+fn test_from_parts() {
+    use crate::Uncased;
+    use alloc::string::String;
+
+    let uncased = Uncased::from_parts(&[("A", "1"), ("B", "2")], "=", "; ");
+    assert_eq!(uncased.as_str(), "A=1; B=2");
+    assert_eq!(uncased, "a=1; b=2");
+
+    let owned = [(String::from("Key"), String::from("Val"))];
+    assert_eq!(Uncased::from_parts(&owned, ": ", ", ").as_str(), "Key: Val");
+}
+
+#[test]
+fn test_as_ptr_round_trip() {
+    let original = UncasedStr::new("Content-Type");
+    let (ptr, len) = (original.as_ptr(), original.len());
+    let rebuilt = unsafe {
+        let bytes = core::slice::from_raw_parts(ptr, len);
+        UncasedStr::new(core::str::from_utf8_unchecked(bytes))
+    };
+
+    assert_eq!(rebuilt.as_str(), "Content-Type");
+    assert_eq!(rebuilt, "content-type");
+    assert_eq!(rebuilt.as_ptr(), original.as_ptr());
+}
+
+#[test]
+fn test_box_from_iter_char() {
+    use alloc::boxed::Box;
+
+    let boxed: Box<UncasedStr> = "Content-Type".chars().collect();
+    assert_eq!(boxed.as_str(), "Content-Type");
+    assert_eq!(&*boxed, "CONTENT-TYPE");
+    assert_eq!(hash(&*boxed), hash(UncasedStr::new("content-type")));
+
+    let empty: Box<UncasedStr> = core::iter::empty().collect();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_split_once_char() {
+    let (key, val) = UncasedStr::new("Key=Val").split_once_char('=').unwrap();
+    assert_eq!((key.as_str(), val.as_str()), ("Key", "Val"));
+
+    let (left, right) = UncasedStr::new("aXbxc").split_once_char('x').unwrap();
+    assert_eq!((left.as_str(), right.as_str()), ("a", "bxc"));
+
+    let (left, right) = UncasedStr::new("aèb").split_once_char('è').unwrap();
+    assert_eq!((left.as_str(), right.as_str()), ("a", "b"));
+
+    let (left, right) = UncasedStr::new("=").split_once_char('=').unwrap();
+    assert!(left.is_empty() && right.is_empty());
+    assert!(UncasedStr::new("").split_once_char('=').is_none());
+}
+
+#[test]
+fn test_as_mut_string() {
+    use crate::Uncased;
+
+    let mut uncased = Uncased::from_borrowed("Hello");
+    uncased.as_mut_string().push('!');
+    assert_eq!(uncased.as_str(), "Hello!");
+    assert_eq!(uncased, "hello!");
+
+    uncased.as_mut_string().insert_str(0, "OH, ");
+    assert_eq!(uncased.as_str(), "OH, Hello!");
+    assert_eq!(uncased, "oh, hello!");
+    assert_eq!(hash(&uncased), hash(UncasedStr::new("Oh, HELLO!")));
+}
+
+#[test]
+fn test_starts_ends_with_any() {
+    let content_type = UncasedStr::new("image/png");
+    assert!(content_type.starts_with_any(["text/", "image/"]));
+    assert!(UncasedStr::new("IMAGE/PNG").starts_with_any(["text/", "image/"]));
+    assert!(!content_type.starts_with_any(["text/", "audio/"]));
+
+    assert!(content_type.ends_with_any(["/jpeg", "/PNG"]));
+    assert!(!content_type.ends_with_any(["/jpeg", "/gif"]));
+    assert!(!content_type.ends_with_any(["a/very/long/image/png"]));
+
+    let owned = [alloc::string::String::from("IMAGE/")];
+    assert!(content_type.starts_with_any(&owned));
+}